@@ -0,0 +1,19 @@
+export interface TinInfo {
+  /** "FNumber", "DNumber", "OrgNumber" or "DufNumber". */
+  variant: string
+  /** "Normal", "HNumber", "Anonymous" or "Synthetic". */
+  kind: string
+  isTestId: boolean
+  /** ISO 8601 birth date, when the number encodes one. */
+  birthDate?: string
+  masked: string
+  value: string
+}
+/**
+ * Parses a person, organisation or DUF number, throwing an error whose
+ * message is the error name (e.g. "InvalidChecksum") when it is not valid.
+ */
+export function parse(s: string): TinInfo
+export function isValid(s: string): boolean
+export function isValidPerson(s: string): boolean
+export function isValidOrg(s: string): boolean
@@ -0,0 +1,60 @@
+use napi::{Error, Result};
+use napi_derive::napi;
+use norwegian_tin_validator::NorwegianTin;
+
+#[napi(object)]
+pub struct TinInfo {
+    /// "FNumber", "DNumber", "OrgNumber" or "DufNumber".
+    pub variant: String,
+    /// "Normal", "HNumber", "Anonymous" or "Synthetic".
+    pub kind: String,
+    pub is_test_id: bool,
+    /// ISO 8601 birth date, when the number encodes one.
+    pub birth_date: Option<String>,
+    pub masked: String,
+    pub value: String,
+}
+
+impl From<NorwegianTin> for TinInfo {
+    fn from(tin: NorwegianTin) -> Self {
+        let variant = match tin {
+            NorwegianTin::FNumber(_) => "FNumber",
+            NorwegianTin::DNumber(_) => "DNumber",
+            NorwegianTin::OrgNumber(_) => "OrgNumber",
+            NorwegianTin::DufNumber(_) => "DufNumber",
+            _ => "Unknown",
+        };
+        TinInfo {
+            variant: variant.to_string(),
+            kind: format!("{:?}", tin.kind()),
+            is_test_id: tin.kind().is_test_id(),
+            birth_date: tin.birth_date().map(|date| date.to_string()),
+            masked: tin.to_string(),
+            value: tin.into(),
+        }
+    }
+}
+
+/// Parses a person, organisation or DUF number, throwing an error whose
+/// message is the error name (e.g. "InvalidChecksum") when it is not valid.
+#[napi]
+pub fn parse(s: String) -> Result<TinInfo> {
+    NorwegianTin::parse(&s)
+        .map(TinInfo::from)
+        .map_err(|err| Error::from_reason(err.to_string()))
+}
+
+#[napi]
+pub fn is_valid(s: String) -> bool {
+    norwegian_tin_validator::is_valid(&s)
+}
+
+#[napi]
+pub fn is_valid_person(s: String) -> bool {
+    norwegian_tin_validator::is_valid_person(&s)
+}
+
+#[napi]
+pub fn is_valid_org(s: String) -> bool {
+    norwegian_tin_validator::is_valid_org(&s)
+}
@@ -0,0 +1,97 @@
+use ::norwegian_tin_validator as tin;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDate;
+
+#[pyclass(name = "NorwegianTin", frozen, eq)]
+#[derive(PartialEq)]
+struct PyNorwegianTin {
+    inner: tin::NorwegianTin,
+}
+
+#[pymethods]
+impl PyNorwegianTin {
+    #[new]
+    fn new(s: &str) -> PyResult<Self> {
+        parse(s)
+    }
+
+    /// "FNumber", "DNumber", "OrgNumber" or "DufNumber".
+    #[getter]
+    fn variant(&self) -> &'static str {
+        match self.inner {
+            tin::NorwegianTin::FNumber(_) => "FNumber",
+            tin::NorwegianTin::DNumber(_) => "DNumber",
+            tin::NorwegianTin::OrgNumber(_) => "OrgNumber",
+            tin::NorwegianTin::DufNumber(_) => "DufNumber",
+            _ => "Unknown",
+        }
+    }
+
+    /// "Normal", "HNumber", "Anonymous" or "Synthetic".
+    #[getter]
+    fn kind(&self) -> String {
+        format!("{:?}", self.inner.kind())
+    }
+
+    #[getter]
+    fn is_test_id(&self) -> bool {
+        self.inner.kind().is_test_id()
+    }
+
+    #[getter]
+    fn birth_date<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyDate>>> {
+        self.inner
+            .birth_date()
+            .map(|date| PyDate::new(py, date.year() as i32, date.month(), date.day()))
+            .transpose()
+    }
+
+    /// The full, unmasked number.
+    #[getter]
+    fn value(&self) -> String {
+        self.inner.into()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("NorwegianTin('{}')", self.inner)
+    }
+}
+
+/// Parses a person, organisation or DUF number, raising `ValueError` with the
+/// error name (e.g. "InvalidChecksum") when it is not valid.
+#[pyfunction]
+fn parse(s: &str) -> PyResult<PyNorwegianTin> {
+    tin::NorwegianTin::parse(s)
+        .map(|inner| PyNorwegianTin { inner })
+        .map_err(|err| PyValueError::new_err(err.to_string()))
+}
+
+#[pyfunction]
+fn is_valid(s: &str) -> bool {
+    tin::is_valid(s)
+}
+
+#[pyfunction]
+fn is_valid_person(s: &str) -> bool {
+    tin::is_valid_person(s)
+}
+
+#[pyfunction]
+fn is_valid_org(s: &str) -> bool {
+    tin::is_valid_org(s)
+}
+
+#[pymodule]
+fn norwegian_tin_validator(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyNorwegianTin>()?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(is_valid, m)?)?;
+    m.add_function(wrap_pyfunction!(is_valid_person, m)?)?;
+    m.add_function(wrap_pyfunction!(is_valid_org, m)?)?;
+    Ok(())
+}
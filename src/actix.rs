@@ -0,0 +1,165 @@
+use std::future::{ready, Ready};
+use std::sync::Arc;
+
+use actix_web::dev::Payload;
+use actix_web::http::header::ContentType;
+use actix_web::http::StatusCode;
+use actix_web::{error, FromRequest, HttpRequest, HttpResponse, ResponseError};
+
+use crate::{NorwegianTin, NorwegianTinError};
+
+type ErrorHandler = dyn Fn(NorwegianTinError, &HttpRequest) -> actix_web::Error + Send + Sync;
+
+/// Extracts and validates a `NorwegianTin` from the single path parameter of
+/// a route, e.g. `/persons/{tin}`. Routes with several parameters must name
+/// the one to use with `TinConfig::param`. Invalid values are rejected with
+/// the `ResponseError` of `NorwegianTinError` (422 Unprocessable Entity),
+/// unless a `TinConfig` with an error handler is registered as app data.
+///
+/// With the `serde` feature, `web::Query` and `web::Json` payloads can also use
+/// `NorwegianTin` fields directly through its `Deserialize` implementation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TinPath(pub NorwegianTin);
+
+/// Configures the path parameter and error response of `TinPath`.
+#[derive(Clone, Default)]
+pub struct TinConfig {
+    param: Option<String>,
+    error_handler: Option<Arc<ErrorHandler>>,
+}
+
+impl TinConfig {
+    /// Names the path parameter holding the TIN, e.g. `"tin"` for
+    /// `/orgs/{org}/persons/{tin}`.
+    pub fn param(mut self, name: impl Into<String>) -> Self {
+        self.param = Some(name.into());
+        self
+    }
+
+    pub fn error_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(NorwegianTinError, &HttpRequest) -> actix_web::Error + Send + Sync + 'static,
+    {
+        self.error_handler = Some(Arc::new(handler));
+        self
+    }
+}
+
+impl FromRequest for TinPath {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let config = req.app_data::<TinConfig>();
+        let params = req.match_info();
+        let raw = match config.and_then(|config| config.param.as_deref()) {
+            Some(name) => params.get(name),
+            None if params.segment_count() == 1 => params.iter().next().map(|(_, raw)| raw),
+            None => None,
+        };
+        let raw =
+            match raw {
+                Some(raw) => raw,
+                None => return ready(Err(error::ErrorInternalServerError(
+                    "TinPath requires a route with exactly one path parameter, or TinConfig::param",
+                ))),
+            };
+        ready(NorwegianTin::parse(raw).map(TinPath).map_err(|err| {
+            match config.and_then(|config| config.error_handler.as_ref()) {
+                Some(handler) => handler(err, req),
+                None => err.into(),
+            }
+        }))
+    }
+}
+
+/// Responds with 422 Unprocessable Entity and a body like
+/// `{"error":"InvalidChecksum","code":"TIN_CHECKSUM"}`.
+impl ResponseError for NorwegianTinError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(crate::http::status_code(self))
+            .unwrap_or(StatusCode::UNPROCESSABLE_ENTITY)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code())
+            .insert_header(ContentType::json())
+            .body(crate::http::error_body(self))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use actix_web::{test, web, App};
+
+    async fn tin(TinPath(tin): TinPath) -> String {
+        tin.to_string()
+    }
+
+    #[actix_web::test]
+    async fn test_tin_path() {
+        let app = test::init_service(App::new().route("/persons/{tin}", web::get().to(tin))).await;
+
+        let req = test::TestRequest::get()
+            .uri("/persons/16057902284")
+            .to_request();
+        let body = test::call_and_read_body(&app, req).await;
+        assert_eq!(body, "160579*****");
+
+        let req = test::TestRequest::get()
+            .uri("/persons/12345678901")
+            .to_request();
+        let response = test::call_service(&app, req).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            test::read_body(response).await,
+            r#"{"error":"InvalidChecksum","code":"TIN_CHECKSUM"}"#
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_named_param() {
+        let route = "/orgs/{org}/persons/{tin}";
+        let app = test::init_service(App::new().route(route, web::get().to(tin))).await;
+        let req = test::TestRequest::get()
+            .uri("/orgs/923609016/persons/16057902284")
+            .to_request();
+        let response = test::call_service(&app, req).await;
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(TinConfig::default().param("tin"))
+                .route(route, web::get().to(tin)),
+        )
+        .await;
+        let req = test::TestRequest::get()
+            .uri("/orgs/923609016/persons/16057902284")
+            .to_request();
+        let body = test::call_and_read_body(&app, req).await;
+        assert_eq!(body, "160579*****");
+    }
+
+    #[actix_web::test]
+    async fn test_error_handler() {
+        let config = TinConfig::default()
+            .error_handler(|err, _| error::ErrorBadRequest(format!("invalid tin: {}", err)));
+        let app = test::init_service(
+            App::new()
+                .app_data(config)
+                .route("/persons/{tin}", web::get().to(tin)),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/persons/1605790228")
+            .to_request();
+        let response = test::call_service(&app, req).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            test::read_body(response).await,
+            "invalid tin: InvalidLength"
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use arrow_array::builder::{BooleanBuilder, UInt8Builder};
+use arrow_array::{Array, BooleanArray, GenericStringArray, OffsetSizeTrait, UInt8Array};
+
+use crate::{ErrorKind, NorwegianTin, NorwegianTinError};
+
+/// Result of validating a string column. Both arrays have the length of the
+/// input and are null where the input is null.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnValidation {
+    /// Whether each value is a valid number.
+    pub valid: BooleanArray,
+    /// The `error_code` of each invalid value, null for valid values.
+    pub errors: UInt8Array,
+}
+
+/// Stable numeric code of an error, as stored in `ColumnValidation::errors`.
+pub fn error_code(err: &NorwegianTinError) -> u8 {
+    match err.kind() {
+        ErrorKind::InvalidLength => 1,
+        ErrorKind::NonNumericValue => 2,
+        ErrorKind::InvalidChecksum => 3,
+        ErrorKind::InvalidDate => 4,
+        ErrorKind::WrongIdentifierType => 5,
+        ErrorKind::InvalidPrefix => 6,
+        ErrorKind::FutureBirthDate => 7,
+        ErrorKind::NotAccepted => 8,
+    }
+}
+
+/// Validates every value of a `StringArray` or `LargeStringArray` in one call.
+/// Polars columns can be passed through their Arrow representation.
+pub fn validate_array<O: OffsetSizeTrait>(array: &GenericStringArray<O>) -> ColumnValidation {
+    let mut valid = BooleanBuilder::with_capacity(array.len());
+    let mut errors = UInt8Builder::with_capacity(array.len());
+    for value in array.iter() {
+        match value.map(NorwegianTin::parse) {
+            None => {
+                valid.append_null();
+                errors.append_null();
+            }
+            Some(Ok(_)) => {
+                valid.append_value(true);
+                errors.append_null();
+            }
+            Some(Err(err)) => {
+                valid.append_value(false);
+                errors.append_value(error_code(&err));
+            }
+        }
+    }
+    ColumnValidation {
+        valid: valid.finish(),
+        errors: errors.finish(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use arrow_array::{LargeStringArray, StringArray};
+
+    #[test]
+    fn test_validate_array() {
+        let array = StringArray::from(vec![
+            Some("16057902284"),
+            Some("16057902285"),
+            None,
+            Some("974760673"),
+            Some("1605790228a"),
+            Some("123"),
+        ]);
+        let result = validate_array(&array);
+        assert_eq!(
+            result.valid,
+            BooleanArray::from(vec![
+                Some(true),
+                Some(false),
+                None,
+                Some(true),
+                Some(false),
+                Some(false)
+            ])
+        );
+        assert_eq!(
+            result.errors,
+            UInt8Array::from(vec![None, Some(3), None, None, Some(2), Some(1)])
+        );
+    }
+
+    #[test]
+    fn test_validate_large_array() {
+        let array = LargeStringArray::from(vec!["70887100797", "201234567800"]);
+        let result = validate_array(&array);
+        assert_eq!(result.valid, BooleanArray::from(vec![true, true]));
+        assert_eq!(result.errors.null_count(), 2);
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+use apache_avro::schema::{Name, NamespaceRef, RecordField};
+use apache_avro::types::Value;
+use apache_avro::{AvroSchemaComponent, Schema};
+
+use crate::{NorwegianTin, NorwegianTinError};
+
+/// The Avro schema of a number: a string of the full digits. The logical
+/// type documents the field for consumers, and readers that do not know it
+/// fall back to a plain string, as the Avro specification requires.
+pub const SCHEMA: &str = r#"{"type":"string","logicalType":"norwegian-tin"}"#;
+
+/// Errors from reading a `NorwegianTin` out of an Avro value.
+#[derive(Debug, PartialEq)]
+pub enum AvroError {
+    /// The value is not an Avro string.
+    UnexpectedType,
+    Invalid(NorwegianTinError),
+}
+
+impl std::fmt::Display for AvroError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AvroError::UnexpectedType => write!(f, "UnexpectedType"),
+            AvroError::Invalid(err) => write!(f, "Invalid({})", err),
+        }
+    }
+}
+
+impl std::error::Error for AvroError {}
+
+impl From<NorwegianTinError> for AvroError {
+    fn from(err: NorwegianTinError) -> Self {
+        AvroError::Invalid(err)
+    }
+}
+
+/// Lets `#[derive(AvroSchema)]` records hold `NorwegianTin` fields, with the
+/// plain string schema `SCHEMA` resolves to. Values are written and read
+/// through the `serde` impls, so they are validated when deserialized.
+impl AvroSchemaComponent for NorwegianTin {
+    fn get_schema_in_ctxt(_: &mut HashSet<Name>, _: NamespaceRef) -> Schema {
+        Schema::String
+    }
+
+    fn get_record_fields_in_ctxt(
+        _: &mut HashSet<Name>,
+        _: NamespaceRef,
+    ) -> Option<Vec<RecordField>> {
+        None
+    }
+}
+
+impl From<NorwegianTin> for Value {
+    fn from(tin: NorwegianTin) -> Value {
+        Value::String(tin.into())
+    }
+}
+
+impl TryFrom<&Value> for NorwegianTin {
+    type Error = AvroError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::String(s) => Ok(NorwegianTin::parse(s)?),
+            // A field with a `["null", "string"]` schema
+            Value::Union(_, inner) => NorwegianTin::try_from(inner.as_ref()),
+            _ => Err(AvroError::UnexpectedType),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use apache_avro::{from_value, to_value, Reader, Writer};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Customer {
+        name: String,
+        tin: NorwegianTin,
+    }
+
+    fn customer_schema() -> Schema {
+        let schema = format!(
+            r#"{{"type":"record","name":"Customer","fields":[
+                {{"name":"name","type":"string"}},
+                {{"name":"tin","type":{}}}
+            ]}}"#,
+            SCHEMA
+        );
+        Schema::parse_str(&schema).unwrap()
+    }
+
+    #[test]
+    fn test_avro_roundtrip() {
+        assert_eq!(Schema::parse_str(SCHEMA).unwrap(), Schema::String);
+
+        let schema = customer_schema();
+        let customer = Customer {
+            name: String::from("Ada"),
+            tin: NorwegianTin::parse("06071732280").unwrap(),
+        };
+        let mut writer = Writer::new(&schema, Vec::new()).unwrap();
+        writer.append_ser(&customer).unwrap();
+        let encoded = writer.into_inner().unwrap();
+        let read: Vec<Customer> = Reader::new(&encoded[..])
+            .unwrap()
+            .map(|value| from_value(&value.unwrap()).unwrap())
+            .collect();
+        assert_eq!(read, vec![customer]);
+
+        let invalid = to_value(Customer {
+            name: String::from("Bob"),
+            tin: NorwegianTin::parse("16057902284").unwrap(),
+        })
+        .unwrap();
+        let invalid = match invalid {
+            Value::Record(mut fields) => {
+                fields[1].1 = Value::String(String::from("16057902285"));
+                Value::Record(fields)
+            }
+            other => other,
+        };
+        assert!(from_value::<Customer>(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_avro_value_conversion() {
+        let tin = NorwegianTin::parse("974760673").unwrap();
+        let value = Value::from(tin);
+        assert_eq!(value, Value::String(String::from("974760673")));
+        assert_eq!(NorwegianTin::try_from(&value).unwrap(), tin);
+        let union = Value::Union(1, Box::new(value));
+        assert_eq!(NorwegianTin::try_from(&union).unwrap(), tin);
+        assert_eq!(
+            NorwegianTin::try_from(&Value::Long(974760673)),
+            Err(AvroError::UnexpectedType)
+        );
+        assert!(matches!(
+            NorwegianTin::try_from(&Value::String(String::from("974760674"))),
+            Err(AvroError::Invalid(_))
+        ));
+    }
+}
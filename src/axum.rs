@@ -0,0 +1,95 @@
+use ::axum::extract::rejection::PathRejection;
+use ::axum::extract::{FromRequestParts, Path};
+use ::axum::http::header::CONTENT_TYPE;
+use ::axum::http::request::Parts;
+use ::axum::http::StatusCode;
+use ::axum::response::{IntoResponse, Response};
+
+use crate::{NorwegianTin, NorwegianTinError};
+
+/// Extracts and validates a `NorwegianTin` from a single path parameter, e.g.
+/// `/persons/{tin}`. Invalid values are rejected with 422 Unprocessable Entity.
+///
+/// With the `serde` feature, `Query` and `Json` payloads can also use
+/// `NorwegianTin` fields directly through its `Deserialize` implementation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TinPath(pub NorwegianTin);
+
+/// Rejection returned by `TinPath`.
+#[derive(Debug)]
+pub enum TinRejection {
+    Invalid(NorwegianTinError),
+    Path(PathRejection),
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for TinPath {
+    type Rejection = TinRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Path(raw) = Path::<String>::from_request_parts(parts, state)
+            .await
+            .map_err(TinRejection::Path)?;
+        NorwegianTin::parse(&raw)
+            .map(TinPath)
+            .map_err(TinRejection::Invalid)
+    }
+}
+
+impl IntoResponse for TinRejection {
+    fn into_response(self) -> Response {
+        match self {
+            TinRejection::Invalid(err) => err.into_response(),
+            TinRejection::Path(rejection) => rejection.into_response(),
+        }
+    }
+}
+
+/// Responds with 422 Unprocessable Entity and a body like
+/// `{"error":"InvalidChecksum","code":"TIN_CHECKSUM"}`.
+impl IntoResponse for NorwegianTinError {
+    fn into_response(self) -> Response {
+        let body = crate::http::error_body(&self);
+        let status = StatusCode::from_u16(crate::http::status_code(&self))
+            .unwrap_or(StatusCode::UNPROCESSABLE_ENTITY);
+        (status, [(CONTENT_TYPE, "application/json")], body).into_response()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use ::axum::body::{to_bytes, Body};
+    use ::axum::http::Request;
+    use ::axum::routing::get;
+    use ::axum::Router;
+    use tower::ServiceExt;
+
+    async fn get_body(uri: &str) -> (StatusCode, String) {
+        let app = Router::new().route(
+            "/persons/{tin}",
+            get(|TinPath(tin): TinPath| async move { tin.to_string() }),
+        );
+        let response = app
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_tin_path() {
+        assert_eq!(
+            get_body("/persons/16057902284").await,
+            (StatusCode::OK, String::from("160579*****"))
+        );
+        assert_eq!(
+            get_body("/persons/12345678901").await,
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                String::from(r#"{"error":"InvalidChecksum","code":"TIN_CHECKSUM"}"#)
+            )
+        );
+    }
+}
@@ -0,0 +1,610 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+
+use crate::{
+    strip_separators, Date, ErrorKind, NorwegianTin, NorwegianTinError, PersonKind, TIN_LENGTH,
+};
+
+/// A parse error tagged with the index of the input it came from.
+pub type IndexedError = (usize, NorwegianTinError);
+
+/// Parses every value, tagging failures with the index of the input they came from.
+pub fn parse_many<I, S>(values: I) -> Vec<Result<NorwegianTin, IndexedError>>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(i, s)| NorwegianTin::parse(s.as_ref()).map_err(|err| (i, err)))
+        .collect()
+}
+
+/// Parses every value across the rayon thread pool. Results keep the order
+/// of the input, like `parse_many`.
+#[cfg(feature = "rayon")]
+pub fn par_parse_many<S>(values: &[S]) -> Vec<Result<NorwegianTin, IndexedError>>
+where
+    S: AsRef<str> + Sync,
+{
+    use rayon::prelude::*;
+
+    values
+        .par_iter()
+        .enumerate()
+        .map(|(i, s)| NorwegianTin::parse(s.as_ref()).map_err(|err| (i, err)))
+        .collect()
+}
+
+/// Verifies digit-ness and both mod-11 checksums of 11 byte ASCII records,
+/// e.g. a fixed-width column. Dates and kinds are not checked, so a `true`
+/// verdict still needs `NorwegianTin::parse` for a full validation.
+///
+/// With the `simd` feature this uses SSSE3 on x86 CPUs that support it,
+/// verifying four records at a time.
+pub fn verify_checksums(records: &[[u8; TIN_LENGTH]]) -> Vec<bool> {
+    let mut out = vec![false; records.len()];
+    #[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
+    {
+        if crate::simd::verify_checksums(records, &mut out) {
+            return out;
+        }
+    }
+    for (record, verdict) in records.iter().zip(out.iter_mut()) {
+        *verdict = verify_checksums_scalar(record);
+    }
+    out
+}
+
+fn verify_checksums_scalar(record: &[u8; TIN_LENGTH]) -> bool {
+    NorwegianTin::scan_person(record).is_ok()
+}
+
+/// Parses every value and splits the results into valid and invalid entries,
+/// both tagged with the index of the input they came from.
+pub fn partition_valid<I, S>(values: I) -> (Vec<(usize, NorwegianTin)>, Vec<IndexedError>)
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut valid = Vec::new();
+    let mut invalid = Vec::new();
+    for (i, s) in values.into_iter().enumerate() {
+        match NorwegianTin::parse(s.as_ref()) {
+            Ok(tin) => valid.push((i, tin)),
+            Err(err) => invalid.push((i, err)),
+        }
+    }
+    (valid, invalid)
+}
+
+/// An input value that failed validation in `dedup_canonical`.
+#[derive(Debug, PartialEq)]
+pub struct RejectedEntry {
+    /// Index of the value in the input.
+    pub index: usize,
+    /// The value as given, before normalization.
+    pub value: String,
+    pub error: NorwegianTinError,
+}
+
+/// Sorts numbers by birth date, oldest first. Numbers without a derivable
+/// birth date keep their relative order after all others.
+pub fn sort_by_birth_date(tins: &mut [NorwegianTin]) {
+    tins.sort_by_key(|tin| {
+        let birth_date = tin.birth_date();
+        (birth_date.is_none(), birth_date)
+    });
+}
+
+/// The numbers born from `from` to `to`, both included, for cohort
+/// selection. Takes owned numbers or references, e.g. `slice.iter()`.
+pub fn filter_born_between<I>(tins: I, from: Date, to: Date) -> impl Iterator<Item = I::Item>
+where
+    I: IntoIterator,
+    I::Item: Borrow<NorwegianTin>,
+{
+    tins.into_iter()
+        .filter(move |tin| tin.borrow().born_between(from, to))
+}
+
+/// Normalizes raw values by dropping whitespace, `.` and `-`, validates them,
+/// and returns the valid numbers sorted by their digits without duplicates,
+/// together with every rejected value.
+pub fn dedup_canonical<I, S>(values: I) -> (Vec<NorwegianTin>, Vec<RejectedEntry>)
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut valid = Vec::new();
+    let mut rejected = Vec::new();
+    for (index, value) in values.into_iter().enumerate() {
+        let value = value.as_ref();
+        match NorwegianTin::parse(&strip_separators(value)) {
+            Ok(tin) => valid.push(tin),
+            Err(error) => rejected.push(RejectedEntry {
+                index,
+                value: value.to_string(),
+                error,
+            }),
+        }
+    }
+    valid.sort_by(|a, b| a.value().cmp(b.value()));
+    valid.dedup();
+    (valid, rejected)
+}
+
+/// Summary of a batch of parse results, e.g. for a migration report.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Stats {
+    pub total: usize,
+    pub f_numbers: usize,
+    pub d_numbers: usize,
+    pub org_numbers: usize,
+    pub duf_numbers: usize,
+    /// Valid F- and D-numbers per category.
+    pub kinds: HashMap<PersonKind, usize>,
+    /// Invalid values per error.
+    pub errors: HashMap<ErrorKind, usize>,
+    /// Earliest and latest derivable birth dates.
+    pub min_birth_date: Option<Date>,
+    pub max_birth_date: Option<Date>,
+}
+
+impl Stats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, result: &Result<NorwegianTin, NorwegianTinError>) {
+        self.total += 1;
+        let tin = match result {
+            Ok(tin) => tin,
+            Err(err) => {
+                *self.errors.entry(err.kind()).or_insert(0) += 1;
+                return;
+            }
+        };
+        match tin {
+            NorwegianTin::FNumber(_) => self.f_numbers += 1,
+            NorwegianTin::DNumber(_) => self.d_numbers += 1,
+            NorwegianTin::OrgNumber(_) => self.org_numbers += 1,
+            NorwegianTin::DufNumber(_) => self.duf_numbers += 1,
+        }
+        if let Some(kind) = tin.person_kind() {
+            *self.kinds.entry(kind).or_insert(0) += 1;
+        }
+        if let Some(birth_date) = tin.birth_date() {
+            self.min_birth_date = Some(
+                self.min_birth_date
+                    .map_or(birth_date, |d| d.min(birth_date)),
+            );
+            self.max_birth_date = Some(
+                self.max_birth_date
+                    .map_or(birth_date, |d| d.max(birth_date)),
+            );
+        }
+    }
+
+    pub fn valid(&self) -> usize {
+        self.total - self.invalid()
+    }
+
+    pub fn invalid(&self) -> usize {
+        self.errors.values().sum()
+    }
+}
+
+impl Extend<Result<NorwegianTin, NorwegianTinError>> for Stats {
+    fn extend<I: IntoIterator<Item = Result<NorwegianTin, NorwegianTinError>>>(&mut self, iter: I) {
+        for result in iter {
+            self.add(&result);
+        }
+    }
+}
+
+impl FromIterator<Result<NorwegianTin, NorwegianTinError>> for Stats {
+    fn from_iter<I: IntoIterator<Item = Result<NorwegianTin, NorwegianTinError>>>(iter: I) -> Self {
+        let mut stats = Stats::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+/// Extension trait validating each item of an iterator of strings lazily.
+pub trait ValidateTins: Iterator + Sized
+where
+    Self::Item: AsRef<str>,
+{
+    fn validate_tins(self) -> ValidateTinsIter<Self> {
+        ValidateTinsIter { inner: self }
+    }
+}
+
+impl<I> ValidateTins for I
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+}
+
+/// Iterator returned by `ValidateTins::validate_tins`.
+#[derive(Debug, Clone)]
+pub struct ValidateTinsIter<I> {
+    inner: I,
+}
+
+impl<I> Iterator for ValidateTinsIter<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = Result<NorwegianTin, NorwegianTinError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|s| NorwegianTin::parse(s.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for ValidateTinsIter<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: AsRef<str>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next_back()
+            .map(|s| NorwegianTin::parse(s.as_ref()))
+    }
+}
+
+impl<I> ExactSizeIterator for ValidateTinsIter<I>
+where
+    I: ExactSizeIterator,
+    I::Item: AsRef<str>,
+{
+}
+
+/// Validates `reader` line by line, yielding 1-based line numbers with each
+/// result. Surrounding whitespace is trimmed and only one line is held in
+/// memory at a time. A line that is not UTF-8, e.g. from a Latin-1 export,
+/// fails with `NonNumericValue` at its first invalid byte. Iteration stops at
+/// the first I/O error, which can then be taken with
+/// `ValidateLines::take_error`.
+pub fn validate_lines<R: BufRead>(reader: R) -> ValidateLines<R> {
+    ValidateLines {
+        reader,
+        line: Vec::new(),
+        line_number: 0,
+        error: None,
+    }
+}
+
+/// Iterator returned by `validate_lines`.
+#[derive(Debug)]
+pub struct ValidateLines<R> {
+    reader: R,
+    line: Vec<u8>,
+    line_number: u64,
+    error: Option<io::Error>,
+}
+
+impl<R> ValidateLines<R> {
+    /// Takes the I/O error that ended the iteration, if any.
+    pub fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+}
+
+impl<R: BufRead> Iterator for ValidateLines<R> {
+    type Item = (u64, Result<NorwegianTin, NorwegianTinError>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.is_some() {
+            return None;
+        }
+        self.line.clear();
+        match self.reader.read_until(b'\n', &mut self.line) {
+            Ok(0) => None,
+            Ok(_) => {
+                self.line_number += 1;
+                let result = match std::str::from_utf8(&self.line) {
+                    Ok(line) => NorwegianTin::parse(line.trim()),
+                    Err(err) => {
+                        let leading = self
+                            .line
+                            .iter()
+                            .take_while(|b| b.is_ascii_whitespace())
+                            .count();
+                        Err(NorwegianTinError::NonNumericValue {
+                            position: err.valid_up_to() - leading,
+                        })
+                    }
+                };
+                Some((self.line_number, result))
+            }
+            Err(err) => {
+                self.error = Some(err);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+
+    #[test]
+    fn test_parse_many() {
+        let results = parse_many(vec!["16057902284", "12345678901", "905661833", "abc"]);
+        assert_eq!(results.len(), 4);
+        assert!(results[0].is_ok());
+        assert_eq!(
+            results[1].as_ref().unwrap_err(),
+            &(
+                1,
+                NorwegianTinError::InvalidChecksum {
+                    position: 9,
+                    expected: Some(1),
+                    found: 0
+                }
+            )
+        );
+        assert!(results[2].is_ok());
+        assert_eq!(
+            results[3].as_ref().unwrap_err(),
+            &(3, NorwegianTinError::InvalidLength { found: 3 })
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_parse_many() {
+        let values: Vec<String> = (0..10_000)
+            .map(|i| match i % 3 {
+                0 => String::from("16057902284"),
+                1 => String::from("12345678901"),
+                _ => String::from("905661833"),
+            })
+            .collect();
+        assert_eq!(par_parse_many(&values), parse_many(&values));
+    }
+
+    #[test]
+    fn test_filter_born_between() {
+        let tins: Vec<NorwegianTin> = ["16057902284", "70887100797", "974760673", "11010000019"]
+            .iter()
+            .map(|s| NorwegianTin::parse(s).unwrap())
+            .collect();
+        let from = Date::new(1975, 1, 1).unwrap();
+        let to = Date::new(1979, 5, 16).unwrap();
+        assert!(tins[0].born_between(from, to));
+        assert!(!tins[0].born_between(from, Date::new(1979, 5, 15).unwrap()));
+        assert!(!tins[2].born_between(from, to));
+        let selected: Vec<&NorwegianTin> = filter_born_between(&tins, from, to).collect();
+        assert_eq!(selected, vec![&tins[0]]);
+        let from = Date::new(1970, 1, 1).unwrap();
+        assert_eq!(filter_born_between(tins, from, to).count(), 2);
+    }
+
+    #[test]
+    fn test_sort_by_birth_date() {
+        let mut tins: Vec<NorwegianTin> = vec![
+            "01010150074",
+            "974760673",
+            "16057902284",
+            "11010000019",
+            "70887100797",
+        ]
+        .into_iter()
+        .map(|s| NorwegianTin::parse(s).unwrap())
+        .collect();
+        sort_by_birth_date(&mut tins);
+        let sorted: Vec<String> = tins.into_iter().map(String::from).collect();
+        assert_eq!(
+            sorted,
+            vec![
+                "70887100797",
+                "16057902284",
+                "01010150074",
+                "974760673",
+                "11010000019"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dedup_canonical() {
+        let values = vec![
+            String::from("905661833"),
+            String::from(" 160579 02284 "),
+            String::from("16057902284"),
+            String::from("905.661.833"),
+            String::from("160579-02285"),
+            String::from(""),
+        ];
+        let (valid, rejected) = dedup_canonical(values);
+        let valid: Vec<String> = valid.into_iter().map(String::from).collect();
+        assert_eq!(valid, vec!["16057902284", "905661833"]);
+        assert_eq!(
+            rejected,
+            vec![
+                RejectedEntry {
+                    index: 4,
+                    value: String::from("160579-02285"),
+                    error: NorwegianTinError::InvalidChecksum {
+                        position: 10,
+                        expected: Some(4),
+                        found: 5,
+                    },
+                },
+                RejectedEntry {
+                    index: 5,
+                    value: String::new(),
+                    error: NorwegianTinError::InvalidLength { found: 0 },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stats() {
+        let values = vec![
+            "16057902284",
+            "01010150074",
+            "70887100797",
+            "22517149261",
+            "974760673",
+            "201234567800",
+            "16057902285",
+            "12345678901",
+            "abc",
+        ];
+        let stats = Stats::from_iter(values.into_iter().validate_tins());
+        assert_eq!(stats.total, 9);
+        assert_eq!((stats.valid(), stats.invalid()), (6, 3));
+        assert_eq!(
+            (
+                stats.f_numbers,
+                stats.d_numbers,
+                stats.org_numbers,
+                stats.duf_numbers
+            ),
+            (3, 1, 1, 1)
+        );
+        assert_eq!(stats.kinds[&PersonKind::Normal], 2);
+        assert_eq!(stats.kinds[&PersonKind::Synthetic], 1);
+        assert_eq!(stats.kinds[&PersonKind::HNumber], 1);
+        assert_eq!(stats.kinds.values().sum::<usize>(), 4);
+        assert_eq!(stats.errors[&ErrorKind::InvalidChecksum], 2);
+        assert_eq!(stats.errors[&ErrorKind::InvalidLength], 1);
+        assert_eq!(stats.min_birth_date, Some(Date::new(1971, 8, 30).unwrap()));
+        assert_eq!(stats.max_birth_date, Some(Date::new(2001, 1, 1).unwrap()));
+        assert_eq!(Stats::from_iter(Vec::new()), Stats::new());
+    }
+
+    #[test]
+    fn test_partition_valid() {
+        let values = vec![
+            String::from("16057902284"),
+            String::from("1605790228a"),
+            String::from("905661833"),
+        ];
+        let (valid, invalid) = partition_valid(&values);
+        assert_eq!(
+            valid.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+            vec![0, 2]
+        );
+        assert_eq!(
+            invalid,
+            vec![(1, NorwegianTinError::NonNumericValue { position: 10 })]
+        );
+    }
+
+    #[test]
+    fn test_validate_tins() {
+        let input = "16057902284\n12345678901\n905661833";
+        let results: Vec<_> = input.lines().validate_tins().collect();
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert_eq!(
+            results[1].as_ref().unwrap_err().kind(),
+            ErrorKind::InvalidChecksum
+        );
+
+        let owned = vec![String::from("905661833"), String::from("905661834")];
+        let valid: Vec<NorwegianTin> = owned.into_iter().validate_tins().flatten().collect();
+        assert_eq!(valid, vec![NorwegianTin::parse("905661833").unwrap()]);
+    }
+
+    #[test]
+    fn test_verify_checksums() {
+        let records: Vec<[u8; TIN_LENGTH]> = vec![
+            *b"16057902284",
+            *b"11010000019",
+            *b"12345678901",
+            *b"1605790228a",
+            *b"16057902/84",
+            // Both checksums hold, but the date is impossible
+            *b"00000000000",
+        ];
+        assert_eq!(
+            verify_checksums(&records),
+            vec![true, true, false, false, false, true]
+        );
+    }
+
+    #[test]
+    fn test_verify_checksums_matches_parse() {
+        let mut records = Vec::new();
+        for seed in 0u64..5_000 {
+            let mut record = [0u8; TIN_LENGTH];
+            let mut x = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+            for b in record.iter_mut() {
+                x ^= x >> 29;
+                x = x.wrapping_mul(0xBF58_476D_1CE4_E5B9);
+                *b = b'0' + (x % 10) as u8;
+            }
+            if seed % 7 == 0 {
+                record[(seed % 11) as usize] ^= 0x40;
+            }
+            records.push(record);
+        }
+        for (record, verdict) in records.iter().zip(verify_checksums(&records)) {
+            assert_eq!(verdict, NorwegianTin::scan_person(record).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_validate_lines() {
+        let input = "16057902284\r\n 905661833 \n\n12345678901";
+        let results: Vec<_> = validate_lines(input.as_bytes()).collect();
+        assert_eq!(
+            results,
+            vec![
+                (1, NorwegianTin::parse("16057902284")),
+                (2, NorwegianTin::parse("905661833")),
+                (3, Err(NorwegianTinError::InvalidLength { found: 0 })),
+                (4, NorwegianTin::parse("12345678901")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_lines_invalid_utf8() {
+        let input = b" 16\xf805790228\n16057902284\n";
+        let results: Vec<_> = validate_lines(&input[..]).collect();
+        assert_eq!(
+            results,
+            vec![
+                (1, Err(NorwegianTinError::NonNumericValue { position: 2 })),
+                (2, NorwegianTin::parse("16057902284")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_lines_io_error() {
+        struct Failing;
+
+        impl io::Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::Other, "disk gone"))
+            }
+        }
+
+        let mut lines = validate_lines(io::BufReader::new(Failing));
+        assert!(lines.next().is_none());
+        assert_eq!(
+            lines.take_error().map(|err| err.kind()),
+            Some(io::ErrorKind::Other)
+        );
+    }
+}
@@ -0,0 +1,224 @@
+// Bloom filter over packed numbers. The hash is a fixed integer mixer rather
+// than `std::hash`, so serialized filters stay valid across builds and
+// platforms.
+
+use std::convert::TryInto;
+
+use crate::set::{pack, TinSet};
+use crate::NorwegianTin;
+
+const MAGIC: &[u8; 4] = b"NTBF";
+const FORMAT_VERSION: u8 = 1;
+const HEADER_LENGTH: usize = 4 + 1 + 1 + 8;
+const MAX_HASHES: u32 = 16;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BloomError {
+    /// The bytes do not start with the filter header.
+    InvalidHeader,
+    UnsupportedVersion(u8),
+    /// The bytes are shorter or longer than the header says.
+    InvalidLength,
+}
+
+impl std::fmt::Display for BloomError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BloomError::InvalidHeader => write!(f, "InvalidHeader"),
+            BloomError::UnsupportedVersion(version) => {
+                write!(f, "UnsupportedVersion({})", version)
+            }
+            BloomError::InvalidLength => write!(f, "InvalidLength"),
+        }
+    }
+}
+
+impl std::error::Error for BloomError {}
+
+/// Probabilistic set of numbers. `might_contain` never returns `false` for
+/// an inserted number, and returns `true` for other numbers at about the
+/// false positive rate the filter was sized for.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BloomFilter {
+    words: Vec<u64>,
+    bits: u64,
+    hashes: u32,
+}
+
+impl BloomFilter {
+    /// An empty filter sized for `expected` numbers at `false_positive_rate`,
+    /// e.g. 0.01 for one in a hundred.
+    pub fn with_capacity(expected: usize, false_positive_rate: f64) -> Self {
+        let n = expected.max(1) as f64;
+        let rate = false_positive_rate.clamp(1e-9, 0.5);
+        let ln2 = std::f64::consts::LN_2;
+        let bits = (-n * rate.ln() / (ln2 * ln2)).ceil().max(64.0) as u64;
+        let hashes = ((bits as f64 / n) * ln2).round() as u32;
+        BloomFilter {
+            words: vec![0; ((bits + 63) / 64) as usize],
+            bits,
+            hashes: hashes.clamp(1, MAX_HASHES),
+        }
+    }
+
+    /// A filter holding every number of `set`.
+    pub fn from_set(set: &TinSet, false_positive_rate: f64) -> Self {
+        let mut filter = Self::with_capacity(set.len(), false_positive_rate);
+        for tin in set.iter() {
+            filter.insert(&tin);
+        }
+        filter
+    }
+
+    pub fn insert(&mut self, tin: &NorwegianTin) {
+        for bit in self.bit_indexes(tin) {
+            self.words[(bit / 64) as usize] |= 1 << (bit % 64);
+        }
+    }
+
+    /// `false` when `tin` was definitely never inserted.
+    pub fn might_contain(&self, tin: &NorwegianTin) -> bool {
+        self.bit_indexes(tin)
+            .all(|bit| self.words[(bit / 64) as usize] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Serializes the filter: a header with the format version, hash count
+    /// and bit count, followed by the bits as little endian words.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LENGTH + self.words.len() * 8);
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(FORMAT_VERSION);
+        bytes.push(self.hashes as u8);
+        bytes.extend_from_slice(&self.bits.to_le_bytes());
+        for word in &self.words {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<BloomFilter, BloomError> {
+        if bytes.len() < HEADER_LENGTH || &bytes[0..4] != MAGIC {
+            return Err(BloomError::InvalidHeader);
+        }
+        if bytes[4] != FORMAT_VERSION {
+            return Err(BloomError::UnsupportedVersion(bytes[4]));
+        }
+        let hashes = bytes[5] as u32;
+        if hashes == 0 || hashes > MAX_HASHES {
+            return Err(BloomError::InvalidHeader);
+        }
+        let bits = u64::from_le_bytes(bytes[6..14].try_into().expect("8 bytes"));
+        let body = &bytes[HEADER_LENGTH..];
+        // `bits` is untrusted, so the word count must not overflow
+        let body_length = bits.checked_add(63).map(|bits| bits / 64 * 8);
+        if bits == 0 || bits > body.len() as u64 * 8 || body_length != Some(body.len() as u64) {
+            return Err(BloomError::InvalidLength);
+        }
+        let words = body
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().expect("8 bytes")))
+            .collect();
+        Ok(BloomFilter {
+            words,
+            bits,
+            hashes,
+        })
+    }
+
+    /// Size of the bit array in bytes.
+    pub fn memory_bytes(&self) -> usize {
+        self.words.len() * 8
+    }
+
+    /// Double hashing: the k indexes are h1 + i * h2.
+    fn bit_indexes(&self, tin: &NorwegianTin) -> impl Iterator<Item = u64> {
+        let key = pack(tin);
+        let h1 = mix(key);
+        let h2 = mix(key ^ 0x9E37_79B9_7F4A_7C15) | 1;
+        let bits = self.bits;
+        (0..self.hashes as u64).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % bits)
+    }
+}
+
+/// The SplitMix64 finalizer.
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+
+    fn org_numbers(count: u32) -> Vec<NorwegianTin> {
+        (0..count * 20)
+            .filter_map(|i| NorwegianTin::parse_org(&format!("{:09}", 900_000_000 + i)).ok())
+            .take(count as usize)
+            .collect()
+    }
+
+    #[test]
+    fn test_bloom_filter() {
+        let tins = org_numbers(1_000);
+        let (inserted, others) = tins.split_at(500);
+        let set: TinSet = inserted.iter().copied().collect();
+        let filter = BloomFilter::from_set(&set, 0.01);
+        assert!(inserted.iter().all(|tin| filter.might_contain(tin)));
+        let false_positives = others
+            .iter()
+            .filter(|tin| filter.might_contain(tin))
+            .count();
+        assert!(false_positives < 25, "{}", false_positives);
+    }
+
+    #[test]
+    fn test_bloom_filter_bytes() {
+        let mut filter = BloomFilter::with_capacity(10, 0.001);
+        filter.insert(&NorwegianTin::parse("16057902284").unwrap());
+        filter.insert(&NorwegianTin::parse("974760673").unwrap());
+        let bytes = filter.to_bytes();
+        assert_eq!(&bytes[0..4], b"NTBF");
+        let decoded = BloomFilter::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, filter);
+        assert!(decoded.might_contain(&NorwegianTin::parse("16057902284").unwrap()));
+
+        assert_eq!(
+            BloomFilter::from_bytes(b"NTB"),
+            Err(BloomError::InvalidHeader)
+        );
+        let mut wrong_version = bytes.clone();
+        wrong_version[4] = 9;
+        assert_eq!(
+            BloomFilter::from_bytes(&wrong_version),
+            Err(BloomError::UnsupportedVersion(9))
+        );
+        assert_eq!(
+            BloomFilter::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(BloomError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn test_bloom_filter_crafted_header() {
+        let mut bytes = b"NTBF\x01\x03".to_vec();
+        bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(
+            BloomFilter::from_bytes(&bytes),
+            Err(BloomError::InvalidLength)
+        );
+        bytes.extend_from_slice(&[0; 8]);
+        assert_eq!(
+            BloomFilter::from_bytes(&bytes),
+            Err(BloomError::InvalidLength)
+        );
+
+        let mut bytes = b"NTBF\x01\x03".to_vec();
+        bytes.extend_from_slice(&65u64.to_le_bytes());
+        bytes.extend_from_slice(&[0; 8]);
+        assert_eq!(
+            BloomFilter::from_bytes(&bytes),
+            Err(BloomError::InvalidLength)
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use serde::Deserialize;
+
+use crate::{NorwegianTin, OrgNumber};
+
+const DEFAULT_BASE_URL: &str = "https://data.brreg.no/enhetsregisteret/api";
+
+/// Basic facts about a unit registered in Enhetsregisteret.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RegisteredEntity {
+    pub org_number: OrgNumber,
+    pub name: String,
+    /// Organisation form code, e.g. "AS" or "BEDR".
+    pub org_form: String,
+    pub org_form_description: String,
+    /// Whether the number belongs to a sub-unit (underenhet) rather than a main unit.
+    pub is_sub_unit: bool,
+    /// The main unit a sub-unit belongs to.
+    pub parent: Option<OrgNumber>,
+}
+
+#[derive(Debug)]
+pub enum BrregError {
+    Http(reqwest::Error),
+    UnexpectedStatus(u16),
+}
+
+impl std::fmt::Display for BrregError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BrregError::Http(err) => write!(f, "Http({})", err),
+            BrregError::UnexpectedStatus(status) => write!(f, "UnexpectedStatus({})", status),
+        }
+    }
+}
+
+impl std::error::Error for BrregError {}
+
+impl From<reqwest::Error> for BrregError {
+    fn from(err: reqwest::Error) -> Self {
+        BrregError::Http(err)
+    }
+}
+
+#[derive(Deserialize)]
+struct OrgForm {
+    kode: String,
+    beskrivelse: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Unit {
+    navn: String,
+    organisasjonsform: OrgForm,
+    overordnet_enhet: Option<String>,
+}
+
+/// Async client for the open Enhetsregisteret API at Brønnøysundregistrene.
+#[derive(Debug, Clone)]
+pub struct BrregClient {
+    http: reqwest::Client,
+    base_url: String,
+}
+
+impl Default for BrregClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BrregClient {
+    pub fn new() -> Self {
+        Self::with_base_url(DEFAULT_BASE_URL)
+    }
+
+    pub fn with_base_url(base_url: &str) -> Self {
+        Self::with_client(reqwest::Client::new(), base_url)
+    }
+
+    pub fn with_client(http: reqwest::Client, base_url: &str) -> Self {
+        BrregClient {
+            http,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Looks up `org` among main units and sub-units. Returns `None` when the
+    /// number is not registered or has been deleted.
+    pub async fn lookup(&self, org: &OrgNumber) -> Result<Option<RegisteredEntity>, BrregError> {
+        if let Some(unit) = self.fetch("enheter", org).await? {
+            return Ok(Some(unit.into_entity(*org, false)));
+        }
+        if let Some(unit) = self.fetch("underenheter", org).await? {
+            return Ok(Some(unit.into_entity(*org, true)));
+        }
+        Ok(None)
+    }
+
+    /// Returns whether `org` is registered in Enhetsregisteret.
+    pub async fn exists(&self, org: &OrgNumber) -> Result<bool, BrregError> {
+        Ok(self.lookup(org).await?.is_some())
+    }
+
+    async fn fetch(&self, register: &str, org: &OrgNumber) -> Result<Option<Unit>, BrregError> {
+        let number: String = NorwegianTin::OrgNumber(*org).into();
+        let response = self
+            .http
+            .get(format!("{}/{}/{}", self.base_url, register, number))
+            .header(reqwest::header::ACCEPT, "application/json")
+            .send()
+            .await?;
+        match response.status().as_u16() {
+            200 => Ok(Some(response.json().await?)),
+            // 410 Gone is returned for deleted units
+            404 | 410 => Ok(None),
+            status => Err(BrregError::UnexpectedStatus(status)),
+        }
+    }
+}
+
+impl Unit {
+    fn into_entity(self, org_number: OrgNumber, is_sub_unit: bool) -> RegisteredEntity {
+        RegisteredEntity {
+            org_number,
+            name: self.navn,
+            org_form: self.organisasjonsform.kode,
+            org_form_description: self.organisasjonsform.beskrivelse,
+            is_sub_unit,
+            parent: self.overordnet_enhet.and_then(|parent| {
+                match NorwegianTin::parse_org(&parent) {
+                    Ok(NorwegianTin::OrgNumber(org)) => Some(org),
+                    _ => None,
+                }
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+
+    #[test]
+    fn test_sub_unit_response() {
+        let body = r#"{
+            "organisasjonsnummer": "973289829",
+            "navn": "EKSEMPEL AS AVD OSLO",
+            "organisasjonsform": { "kode": "BEDR", "beskrivelse": "Underenhet til næringsdrivende og offentlig forvaltning" },
+            "overordnetEnhet": "905661833"
+        }"#;
+        let unit: Unit = serde_json::from_str(body).unwrap();
+        let org = *NorwegianTin::parse_org("973289829")
+            .unwrap()
+            .as_org()
+            .unwrap();
+        let entity = unit.into_entity(org, true);
+        assert_eq!(entity.name, "EKSEMPEL AS AVD OSLO");
+        assert_eq!(entity.org_form, "BEDR");
+        assert!(entity.is_sub_unit);
+        assert_eq!(
+            entity.parent.map(|p| NorwegianTin::OrgNumber(p).into()),
+            Some(String::from("905661833"))
+        );
+    }
+}
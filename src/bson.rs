@@ -0,0 +1,124 @@
+use std::convert::TryFrom;
+
+use mongo_bson::spec::ElementType;
+use mongo_bson::Bson;
+
+use crate::{NorwegianTin, NorwegianTinError};
+
+/// Errors from reading a `NorwegianTin` out of a BSON value.
+#[derive(Debug, PartialEq)]
+pub enum BsonError {
+    /// The value is not a BSON string.
+    UnexpectedType(ElementType),
+    Invalid(NorwegianTinError),
+}
+
+impl std::fmt::Display for BsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BsonError::UnexpectedType(ty) => write!(f, "UnexpectedType({:?})", ty),
+            BsonError::Invalid(err) => write!(f, "Invalid({})", err),
+        }
+    }
+}
+
+impl std::error::Error for BsonError {}
+
+impl From<NorwegianTinError> for BsonError {
+    fn from(err: NorwegianTinError) -> Self {
+        BsonError::Invalid(err)
+    }
+}
+
+/// Stored as the full digit string, the same form the `serde` impls use, so
+/// documents written either way can be queried by plain string equality.
+impl From<NorwegianTin> for Bson {
+    fn from(tin: NorwegianTin) -> Bson {
+        Bson::String(tin.into())
+    }
+}
+
+impl TryFrom<&Bson> for NorwegianTin {
+    type Error = BsonError;
+
+    fn try_from(value: &Bson) -> Result<Self, Self::Error> {
+        match value {
+            Bson::String(s) => Ok(NorwegianTin::parse(s)?),
+            other => Err(BsonError::UnexpectedType(other.element_type())),
+        }
+    }
+}
+
+impl TryFrom<Bson> for NorwegianTin {
+    type Error = BsonError;
+
+    fn try_from(value: Bson) -> Result<Self, Self::Error> {
+        NorwegianTin::try_from(&value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use mongo_bson::{doc, from_document, to_document};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Customer {
+        name: String,
+        tin: NorwegianTin,
+    }
+
+    #[test]
+    fn test_bson_conversion() {
+        let tin = NorwegianTin::parse("06071732280").unwrap();
+        let bson = Bson::from(tin);
+        assert_eq!(bson, Bson::String(String::from("06071732280")));
+        assert_eq!(NorwegianTin::try_from(bson).unwrap(), tin);
+    }
+
+    #[test]
+    fn test_bson_conversion_invalid() {
+        let tests = vec![
+            (
+                Bson::String("16057902285".to_string()),
+                BsonError::Invalid(NorwegianTinError::InvalidChecksum {
+                    position: 10,
+                    expected: Some(4),
+                    found: 5,
+                }),
+            ),
+            (
+                Bson::Int64(6071732280),
+                BsonError::UnexpectedType(ElementType::Int64),
+            ),
+            (Bson::Null, BsonError::UnexpectedType(ElementType::Null)),
+            (
+                Bson::Array(vec![Bson::String(String::from("16057902284"))]),
+                BsonError::UnexpectedType(ElementType::Array),
+            ),
+        ];
+        for (value, expected) in tests {
+            assert_eq!(NorwegianTin::try_from(&value).unwrap_err(), expected);
+        }
+    }
+
+    #[test]
+    fn test_document_roundtrip() {
+        let customer = Customer {
+            name: "Ola Nordmann".to_string(),
+            tin: NorwegianTin::parse("16057902284").unwrap(),
+        };
+        let document = to_document(&customer).unwrap();
+        assert_eq!(
+            document,
+            doc! { "name": "Ola Nordmann", "tin": "16057902284" }
+        );
+        assert_eq!(from_document::<Customer>(document).unwrap(), customer);
+
+        let invalid = doc! { "name": "Ola Nordmann", "tin": "16057902285" };
+        assert!(from_document::<Customer>(invalid).is_err());
+        let numeric = doc! { "name": "Ola Nordmann", "tin": 16057902284i64 };
+        assert!(from_document::<Customer>(numeric).is_err());
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{Date, Gender, NorwegianTin};
+
+/// Attributes a person asserts about themselves, to check against their
+/// number with `verify`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Claims {
+    birth_date: Option<Date>,
+    gender: Option<Gender>,
+    minimum_age: Option<(u16, Date)>,
+}
+
+impl Claims {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn birth_date(mut self, date: Date) -> Self {
+        self.birth_date = Some(date);
+        self
+    }
+
+    pub fn gender(mut self, gender: Gender) -> Self {
+        self.gender = Some(gender);
+        self
+    }
+
+    /// The person is at least `years` old on `today`.
+    pub fn minimum_age(mut self, years: u16, today: Date) -> Self {
+        self.minimum_age = Some((years, today));
+        self
+    }
+}
+
+/// How a single claim compares to the number.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ClaimOutcome {
+    Match,
+    Mismatch,
+    /// The number does not encode the attribute, e.g. the gender of a new
+    /// scheme number or anything about an organisation number.
+    Unknown,
+}
+
+/// The outcome of every claim, `None` for attributes that were not claimed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ClaimReport {
+    pub birth_date: Option<ClaimOutcome>,
+    pub gender: Option<ClaimOutcome>,
+    pub minimum_age: Option<ClaimOutcome>,
+}
+
+impl ClaimReport {
+    /// Whether every claim was confirmed. `Unknown` does not count as a match.
+    pub fn all_match(&self) -> bool {
+        [self.birth_date, self.gender, self.minimum_age]
+            .iter()
+            .flatten()
+            .all(|outcome| *outcome == ClaimOutcome::Match)
+    }
+
+    /// Whether any claim contradicts the number.
+    pub fn any_mismatch(&self) -> bool {
+        [self.birth_date, self.gender, self.minimum_age].contains(&Some(ClaimOutcome::Mismatch))
+    }
+}
+
+/// Checks each of `claims` against `tin`.
+pub fn verify(tin: &NorwegianTin, claims: &Claims) -> ClaimReport {
+    let person = tin.as_person();
+    let birth_date = person.and_then(|person| person.birth_date());
+    let outcome = |matches: bool| {
+        if matches {
+            ClaimOutcome::Match
+        } else {
+            ClaimOutcome::Mismatch
+        }
+    };
+    ClaimReport {
+        birth_date: claims.birth_date.map(|claimed| match (person, birth_date) {
+            (_, Some(date)) => outcome(date == claimed),
+            // Without the century only the day, month and two digit year tell
+            (Some(person), None) => {
+                if person.matches_birth_date(claimed.day, claimed.month, claimed.year) {
+                    ClaimOutcome::Unknown
+                } else {
+                    ClaimOutcome::Mismatch
+                }
+            }
+            (None, None) => ClaimOutcome::Unknown,
+        }),
+        gender: claims.gender.map(|claimed| match tin.gender() {
+            Some(gender) => outcome(gender == claimed),
+            None => ClaimOutcome::Unknown,
+        }),
+        minimum_age: claims.minimum_age.map(|(years, today)| match birth_date {
+            Some(date) => outcome(age_on(date, today) >= years as i32),
+            None => ClaimOutcome::Unknown,
+        }),
+    }
+}
+
+/// Completed years from `birth` to `today`, negative when born later.
+fn age_on(birth: Date, today: Date) -> i32 {
+    let years = today.year as i32 - birth.year as i32;
+    if (today.month, today.day) < (birth.month, birth.day) {
+        years - 1
+    } else {
+        years
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+
+    #[test]
+    fn test_verify() {
+        // Born 16 May 1979, female
+        let tin = NorwegianTin::parse("16057902284").unwrap();
+        let claims = Claims::new()
+            .birth_date(Date::new(1979, 5, 16).unwrap())
+            .gender(Gender::Female)
+            .minimum_age(18, Date::new(2024, 1, 1).unwrap());
+        let report = verify(&tin, &claims);
+        assert!(report.all_match());
+        assert!(!report.any_mismatch());
+
+        let claims = Claims::new()
+            .birth_date(Date::new(1979, 5, 17).unwrap())
+            .gender(Gender::Male)
+            .minimum_age(45, Date::new(2024, 5, 15).unwrap());
+        assert_eq!(
+            verify(&tin, &claims),
+            ClaimReport {
+                birth_date: Some(ClaimOutcome::Mismatch),
+                gender: Some(ClaimOutcome::Mismatch),
+                minimum_age: Some(ClaimOutcome::Mismatch),
+            }
+        );
+        let birthday = Claims::new().minimum_age(45, Date::new(2024, 5, 16).unwrap());
+        assert_eq!(
+            verify(&tin, &birthday).minimum_age,
+            Some(ClaimOutcome::Match)
+        );
+
+        let report = verify(&tin, &Claims::new().gender(Gender::Female));
+        assert_eq!(report.birth_date, None);
+        assert!(report.all_match());
+    }
+
+    #[test]
+    fn test_verify_unknown() {
+        // A new scheme number, born 11 January in an unknown century
+        let tin = NorwegianTin::parse("11010000019").unwrap();
+        let claims = Claims::new()
+            .birth_date(Date::new(2000, 1, 11).unwrap())
+            .gender(Gender::Male)
+            .minimum_age(18, Date::new(2024, 1, 1).unwrap());
+        let report = verify(&tin, &claims);
+        assert_eq!(report.birth_date, Some(ClaimOutcome::Unknown));
+        assert_eq!(report.gender, Some(ClaimOutcome::Unknown));
+        assert_eq!(report.minimum_age, Some(ClaimOutcome::Unknown));
+        assert!(!report.all_match());
+        assert!(!report.any_mismatch());
+
+        let claims = Claims::new().birth_date(Date::new(2000, 1, 12).unwrap());
+        assert_eq!(
+            verify(&tin, &claims).birth_date,
+            Some(ClaimOutcome::Mismatch)
+        );
+
+        let org = NorwegianTin::parse("974760673").unwrap();
+        let report = verify(&org, &Claims::new().gender(Gender::Male));
+        assert_eq!(report.gender, Some(ClaimOutcome::Unknown));
+    }
+}
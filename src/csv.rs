@@ -0,0 +1,230 @@
+use std::io;
+
+use crate::{NorwegianTin, NorwegianTinError};
+
+/// Selects the column holding the values to validate.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Column {
+    Index(usize),
+    /// A header name; requires the reader to have headers.
+    Name(String),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CsvOptions {
+    column: Column,
+    trim: bool,
+    skip_empty: bool,
+}
+
+impl CsvOptions {
+    pub fn new(column: Column) -> Self {
+        CsvOptions {
+            column,
+            trim: true,
+            skip_empty: false,
+        }
+    }
+
+    /// Trims surrounding whitespace before validating. On by default.
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Leaves out rows whose value is empty instead of reporting them as invalid.
+    pub fn skip_empty(mut self, skip: bool) -> Self {
+        self.skip_empty = skip;
+        self
+    }
+}
+
+/// The validation outcome of a single CSV row.
+#[derive(Debug, PartialEq)]
+pub struct RowOutcome {
+    /// The line in the input where the row starts.
+    pub line: u64,
+    pub value: String,
+    pub result: Result<NorwegianTin, NorwegianTinError>,
+}
+
+#[derive(Debug)]
+pub enum CsvError {
+    Csv(::csv::Error),
+    MissingColumn(Column),
+}
+
+impl std::fmt::Display for CsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvError::Csv(err) => write!(f, "Csv({})", err),
+            CsvError::MissingColumn(column) => write!(f, "MissingColumn({:?})", column),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {}
+
+impl From<::csv::Error> for CsvError {
+    fn from(err: ::csv::Error) -> Self {
+        CsvError::Csv(err)
+    }
+}
+
+/// Validates the selected column of every row.
+pub fn validate_csv<R: io::Read>(
+    reader: &mut ::csv::Reader<R>,
+    options: &CsvOptions,
+) -> Result<Vec<RowOutcome>, CsvError> {
+    let index = column_index(reader, &options.column)?;
+    let mut outcomes = Vec::new();
+    for record in reader.records() {
+        let record = record?;
+        if let Some(outcome) = validate_record(&record, index, options)? {
+            outcomes.push(outcome);
+        }
+    }
+    Ok(outcomes)
+}
+
+/// Validates like `validate_csv`, and copies every row to `writer` with two
+/// extra columns: `tin_valid` ("true"/"false") and `tin_error` (the error
+/// name, empty for valid rows). Rows left out by `skip_empty` are copied with
+/// both columns empty.
+pub fn annotate_csv<R: io::Read, W: io::Write>(
+    reader: &mut ::csv::Reader<R>,
+    writer: &mut ::csv::Writer<W>,
+    options: &CsvOptions,
+) -> Result<Vec<RowOutcome>, CsvError> {
+    let index = column_index(reader, &options.column)?;
+    if reader.has_headers() {
+        let mut headers = reader.headers()?.clone();
+        headers.push_field("tin_valid");
+        headers.push_field("tin_error");
+        writer.write_record(&headers)?;
+    }
+    let mut outcomes = Vec::new();
+    for record in reader.records() {
+        let mut record = record?;
+        match validate_record(&record, index, options)? {
+            Some(outcome) => {
+                let error = match &outcome.result {
+                    Ok(_) => String::new(),
+                    Err(err) => err.to_string(),
+                };
+                record.push_field(if outcome.result.is_ok() {
+                    "true"
+                } else {
+                    "false"
+                });
+                record.push_field(&error);
+                outcomes.push(outcome);
+            }
+            None => {
+                record.push_field("");
+                record.push_field("");
+            }
+        }
+        writer.write_record(&record)?;
+    }
+    writer.flush().map_err(::csv::Error::from)?;
+    Ok(outcomes)
+}
+
+fn column_index<R: io::Read>(
+    reader: &mut ::csv::Reader<R>,
+    column: &Column,
+) -> Result<usize, CsvError> {
+    match column {
+        Column::Index(index) => Ok(*index),
+        Column::Name(name) => {
+            if !reader.has_headers() {
+                return Err(CsvError::MissingColumn(column.clone()));
+            }
+            reader
+                .headers()?
+                .iter()
+                .position(|header| header == name)
+                .ok_or_else(|| CsvError::MissingColumn(column.clone()))
+        }
+    }
+}
+
+fn validate_record(
+    record: &::csv::StringRecord,
+    index: usize,
+    options: &CsvOptions,
+) -> Result<Option<RowOutcome>, CsvError> {
+    let raw = record
+        .get(index)
+        .ok_or(CsvError::MissingColumn(Column::Index(index)))?;
+    let value = if options.trim { raw.trim() } else { raw };
+    if options.skip_empty && value.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(RowOutcome {
+        line: record.position().map_or(0, |p| p.line()),
+        value: value.to_string(),
+        result: NorwegianTin::parse(value),
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use crate::ErrorKind;
+
+    const INPUT: &str = "name,tin\nAda, 16057902284\nBob,12345678901\nEve,\nAcme,905661833\n";
+
+    #[test]
+    fn test_validate_csv() {
+        let mut reader = ::csv::Reader::from_reader(INPUT.as_bytes());
+        let options = CsvOptions::new(Column::Name(String::from("tin")));
+        let outcomes = validate_csv(&mut reader, &options).unwrap();
+        assert_eq!(outcomes.len(), 4);
+        assert_eq!(outcomes[0].line, 2);
+        assert_eq!(outcomes[0].value, "16057902284");
+        assert!(outcomes[0].result.is_ok());
+        assert_eq!(
+            outcomes[1].result.as_ref().unwrap_err().kind(),
+            ErrorKind::InvalidChecksum
+        );
+        assert_eq!(
+            outcomes[2].result,
+            Err(NorwegianTinError::InvalidLength { found: 0 })
+        );
+
+        let mut reader = ::csv::Reader::from_reader(INPUT.as_bytes());
+        let options = CsvOptions::new(Column::Index(1)).skip_empty(true);
+        let outcomes = validate_csv(&mut reader, &options).unwrap();
+        assert_eq!(outcomes.len(), 3);
+        assert_eq!(outcomes[2].line, 5);
+    }
+
+    #[test]
+    fn test_missing_column() {
+        let mut reader = ::csv::Reader::from_reader(INPUT.as_bytes());
+        let options = CsvOptions::new(Column::Name(String::from("fnr")));
+        assert!(matches!(
+            validate_csv(&mut reader, &options),
+            Err(CsvError::MissingColumn(_))
+        ));
+    }
+
+    #[test]
+    fn test_annotate_csv() {
+        let mut reader = ::csv::Reader::from_reader(INPUT.as_bytes());
+        let mut writer = ::csv::Writer::from_writer(Vec::new());
+        let options = CsvOptions::new(Column::Name(String::from("tin"))).skip_empty(true);
+        annotate_csv(&mut reader, &mut writer, &options).unwrap();
+        let output = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+        assert_eq!(
+            output,
+            "name,tin,tin_valid,tin_error\n\
+             Ada, 16057902284,true,\n\
+             Bob,12345678901,false,InvalidChecksum\n\
+             Eve,,,\n\
+             Acme,905661833,true,\n"
+        );
+    }
+}
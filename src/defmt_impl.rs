@@ -0,0 +1,58 @@
+use defmt::{Format, Formatter};
+
+use crate::{
+    mask_label, Date, NorwegianTin, NorwegianTinError, OrgNumber, PersonKind, PersonNumber,
+};
+
+// Masked like `Display`, so logs from the device never hold a full person
+// number. Only the variant name of errors is shown, for the same reason.
+impl Format for NorwegianTin {
+    fn format(&self, f: Formatter) {
+        write_masked(f, self.kind(), self.value());
+    }
+}
+
+impl Format for PersonNumber {
+    fn format(&self, f: Formatter) {
+        write_masked(f, self.kind, &self.value);
+    }
+}
+
+/// Organisation numbers are public, so they are shown in full.
+impl Format for OrgNumber {
+    fn format(&self, f: Formatter) {
+        let mut digits = [b'0'; 9];
+        for (c, d) in digits.iter_mut().zip(&self.value) {
+            *c += d;
+        }
+        let digits = core::str::from_utf8(&digits).unwrap_or("");
+        defmt::write!(f, "{=str}", digits);
+    }
+}
+
+impl Format for NorwegianTinError {
+    fn format(&self, f: Formatter) {
+        self.kind().format(f);
+    }
+}
+
+impl Format for Date {
+    fn format(&self, f: Formatter) {
+        defmt::write!(
+            f,
+            "{=u16:04}-{=u8:02}-{=u8:02}",
+            self.year,
+            self.month,
+            self.day
+        );
+    }
+}
+
+fn write_masked(f: Formatter, kind: PersonKind, digits: &[u8]) {
+    let mut shown = [b'0'; 6];
+    for (c, d) in shown.iter_mut().zip(digits) {
+        *c += d;
+    }
+    let shown = core::str::from_utf8(&shown).unwrap_or("");
+    defmt::write!(f, "{=str}{=str}*****", mask_label(kind), shown);
+}
@@ -0,0 +1,216 @@
+use std::str::FromStr;
+
+use crate::{Date, NorwegianTinError, Tin};
+
+const CPR_LENGTH: usize = 10;
+const SEQUENCE_CPR_CHECKSUM_DIGITS: &[u8; 10] = &[4, 3, 2, 7, 6, 5, 4, 3, 2, 1];
+
+/// A Danish CPR number (personnummer).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct CprNumber {
+    value: [u8; CPR_LENGTH],
+}
+
+impl CprNumber {
+    /// Parses "DDMMYY-SSSS" or "DDMMYYSSSS", validating the date with the
+    /// century given by the seventh digit. The legacy mod-11 check is not
+    /// applied, since numbers issued after 2007 do not always satisfy it.
+    pub fn parse(s: &str) -> Result<CprNumber, NorwegianTinError> {
+        let bytes = s.as_bytes();
+        let mut digits = [0u8; CPR_LENGTH];
+        let serial = match bytes.len() {
+            10 => &bytes[6..],
+            11 if bytes[6] == b'-' => &bytes[7..],
+            11 => return Err(NorwegianTinError::NonNumericValue { position: 6 }),
+            found => return Err(NorwegianTinError::InvalidLength { found }),
+        };
+        for (i, &b) in bytes[0..6].iter().chain(serial.iter()).enumerate() {
+            if !b.is_ascii_digit() {
+                let position = if i < 6 {
+                    i
+                } else {
+                    i + bytes.len() - CPR_LENGTH
+                };
+                return Err(NorwegianTinError::NonNumericValue { position });
+            }
+            digits[i] = b - b'0';
+        }
+
+        let cpr = CprNumber { value: digits };
+        Date::new(
+            cpr.birth_year(),
+            digits[2] * 10 + digits[3],
+            digits[0] * 10 + digits[1],
+        )?;
+        Ok(cpr)
+    }
+
+    /// Parses like `parse`, but also requires the legacy mod-11 check.
+    pub fn parse_strict(s: &str) -> Result<CprNumber, NorwegianTinError> {
+        let cpr = Self::parse(s)?;
+        if !cpr.passes_mod11() {
+            let found = cpr.value[CPR_LENGTH - 1];
+            let expected = (found + 11 - cpr.mod11_remainder()) % 11;
+            return Err(NorwegianTinError::InvalidChecksum {
+                position: s.len() - 1,
+                expected: if expected == 10 { None } else { Some(expected) },
+                found,
+            });
+        }
+        Ok(cpr)
+    }
+
+    /// Returns whether the number satisfies the legacy mod-11 check.
+    pub fn passes_mod11(&self) -> bool {
+        self.mod11_remainder() == 0
+    }
+
+    fn mod11_remainder(&self) -> u8 {
+        let sum: u32 = SEQUENCE_CPR_CHECKSUM_DIGITS
+            .iter()
+            .zip(self.value.iter())
+            .map(|(&w, &d)| w as u32 * d as u32)
+            .sum();
+        (sum % 11) as u8
+    }
+
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// The four digit birth year, using the century rules of the seventh digit.
+    pub fn birth_year(&self) -> u16 {
+        let year = self.value[4] as u16 * 10 + self.value[5] as u16;
+        let century = match (self.value[6], year) {
+            (0..=3, _) => 1900,
+            (4 | 9, 0..=36) => 2000,
+            (4 | 9, _) => 1900,
+            (_, 0..=57) => 2000,
+            (_, _) => 1800,
+        };
+        century + year
+    }
+
+    pub fn birth_date(&self) -> Date {
+        let digits = &self.value;
+        Date {
+            year: self.birth_year(),
+            month: digits[2] * 10 + digits[3],
+            day: digits[0] * 10 + digits[1],
+        }
+    }
+}
+
+impl Tin for CprNumber {
+    type Kind = ();
+
+    fn kind(&self) {}
+
+    fn canonical_string(&self) -> String {
+        (*self).into()
+    }
+
+    fn birth_date(&self) -> Option<Date> {
+        Some(CprNumber::birth_date(self))
+    }
+}
+
+/// Returns whether `s` is a valid CPR number.
+pub fn is_valid(s: &str) -> bool {
+    CprNumber::parse(s).is_ok()
+}
+
+impl FromStr for CprNumber {
+    type Err = NorwegianTinError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl From<CprNumber> for String {
+    fn from(cpr: CprNumber) -> String {
+        cpr.value.iter().map(|&d| (d + b'0') as char).collect()
+    }
+}
+
+impl AsRef<[u8]> for CprNumber {
+    fn as_ref(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+impl std::fmt::Display for CprNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s: String = (*self).into();
+        // Masking the last 4 digits for privacy
+        write!(f, "{}-****", &s[0..6])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use crate::ErrorKind;
+
+    #[test]
+    fn test_valid_cpr() {
+        let numbers = vec!["070761-4005", "0707614005", "0101001237", "290200-4001"];
+        for number in numbers {
+            assert!(CprNumber::parse(number).is_ok());
+            assert!(CprNumber::parse_strict(number).is_ok());
+            assert!(is_valid(number));
+        }
+        // Issued without a valid mod-11 check digit
+        let cpr = CprNumber::parse("070761-4006").unwrap();
+        assert!(!cpr.passes_mod11());
+        assert_eq!(
+            CprNumber::parse_strict("070761-4006").unwrap_err().kind(),
+            ErrorKind::InvalidChecksum
+        );
+    }
+
+    #[test]
+    fn test_century() {
+        let numbers = vec![
+            ("070761-1234", 1961),
+            ("070736-4000", 2036),
+            ("070761-4005", 1961),
+            ("070757-5000", 2057),
+            ("070858-8000", 1858),
+            ("070736-9000", 2036),
+            ("070737-9000", 1937),
+        ];
+        for (number, year) in numbers {
+            let cpr = CprNumber::parse(number).unwrap();
+            assert_eq!(cpr.birth_year(), year);
+            assert_eq!(cpr.birth_date().year(), year);
+        }
+    }
+
+    #[test]
+    fn test_invalid_cpr() {
+        // 1900 was not a leap year
+        assert_eq!(
+            CprNumber::parse("290200-1234").unwrap_err().kind(),
+            ErrorKind::InvalidDate
+        );
+        assert_eq!(
+            CprNumber::parse("321261-1234").unwrap_err().kind(),
+            ErrorKind::InvalidDate
+        );
+        assert_eq!(
+            CprNumber::parse("070761/4005").unwrap_err().kind(),
+            ErrorKind::NonNumericValue
+        );
+        assert_eq!(
+            CprNumber::parse("07076140").unwrap_err().kind(),
+            ErrorKind::InvalidLength
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let cpr = CprNumber::parse("0707614005").unwrap();
+        assert_eq!(format!("{}", cpr), "070761-****");
+    }
+}
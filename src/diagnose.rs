@@ -0,0 +1,112 @@
+use crate::{
+    Date, NorwegianTin, NorwegianTinError, PersonKind, PersonNumber, PersonVariant, TinShape,
+    DUF_LENGTH, ORG_LENGTH, TIN_LENGTH,
+};
+
+/// What could still be read from an input that failed to parse, to help
+/// prioritise data quality fixes. Every hint is `None` when that part of the
+/// input does not decode.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Diagnosis {
+    pub error: NorwegianTinError,
+    /// The kind of number the length and digits suggest, ignoring the date
+    /// and check digits.
+    pub shape: Option<TinShape>,
+    pub variant: Option<PersonVariant>,
+    /// The category of person numbers, and `Normal` or `Synthetic` for
+    /// organisation numbers.
+    pub kind: Option<PersonKind>,
+    /// The birth date of person numbers, with the century taken from the
+    /// individual number as in the classic scheme.
+    pub birth_date: Option<Date>,
+}
+
+/// Parses like `NorwegianTin::parse`, and on failure decodes whatever the
+/// input still tells, e.g. that a number with a wrong check digit looks like
+/// a synthetic F-number born 1979-05-16.
+pub fn diagnose(s: &str) -> Result<NorwegianTin, Diagnosis> {
+    let error = match NorwegianTin::parse(s) {
+        Ok(tin) => return Ok(tin),
+        Err(error) => error,
+    };
+    let mut diagnosis = Diagnosis {
+        error,
+        shape: None,
+        variant: None,
+        kind: None,
+        birth_date: None,
+    };
+    let bytes = s.as_bytes();
+    if !bytes.iter().all(|b| b.is_ascii_digit()) {
+        return Err(diagnosis);
+    }
+    match bytes.len() {
+        TIN_LENGTH => {
+            diagnosis.shape = Some(TinShape::Person);
+            let mut value = [0u8; TIN_LENGTH];
+            for (d, b) in value.iter_mut().zip(bytes) {
+                *d = b - b'0';
+            }
+            diagnosis.variant = match value[0] {
+                0..=3 => Some(PersonVariant::FNumber),
+                4..=7 => Some(PersonVariant::DNumber),
+                _ => None,
+            };
+            diagnosis.kind = NorwegianTin::check_kind(value[2]);
+            if let (Some(_), Some(kind)) = (diagnosis.variant, diagnosis.kind) {
+                let person = PersonNumber { kind, value };
+                diagnosis.birth_date = person.classic_century().and_then(|century| {
+                    Date::new(century + person.year() as u16, person.month(), person.day()).ok()
+                });
+            }
+        }
+        ORG_LENGTH => {
+            diagnosis.shape = Some(TinShape::Org);
+            diagnosis.kind = match bytes[0] {
+                b'2' | b'3' => Some(PersonKind::Synthetic),
+                _ => Some(PersonKind::Normal),
+            };
+        }
+        DUF_LENGTH => diagnosis.shape = Some(TinShape::Duf),
+        _ => {}
+    }
+    Err(diagnosis)
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use crate::ErrorKind;
+
+    #[test]
+    fn test_diagnose() {
+        assert!(diagnose("16057902284").is_ok());
+
+        let diagnosis = diagnose("16857902285").unwrap_err();
+        assert_eq!(diagnosis.error.kind(), ErrorKind::InvalidChecksum);
+        assert_eq!(diagnosis.shape, Some(TinShape::Person));
+        assert_eq!(diagnosis.variant, Some(PersonVariant::FNumber));
+        assert_eq!(diagnosis.kind, Some(PersonKind::Synthetic));
+        assert_eq!(diagnosis.birth_date, Date::new(1979, 5, 16).ok());
+
+        let diagnosis = diagnose("70887100798").unwrap_err();
+        assert_eq!(diagnosis.variant, Some(PersonVariant::DNumber));
+        assert_eq!(diagnosis.birth_date, Date::new(1971, 8, 30).ok());
+
+        // 31 April does not exist
+        let diagnosis = diagnose("31047900000").unwrap_err();
+        assert_eq!(diagnosis.kind, Some(PersonKind::Normal));
+        assert_eq!(diagnosis.birth_date, None);
+
+        let diagnosis = diagnose("974760674").unwrap_err();
+        assert_eq!(diagnosis.shape, Some(TinShape::Org));
+        assert_eq!(diagnosis.kind, Some(PersonKind::Normal));
+
+        let diagnosis = diagnose("1605790228x").unwrap_err();
+        assert_eq!(
+            diagnosis.error,
+            NorwegianTinError::NonNumericValue { position: 10 }
+        );
+        assert_eq!(diagnosis.shape, None);
+    }
+}
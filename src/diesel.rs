@@ -0,0 +1,87 @@
+use std::io::Write;
+
+use ::diesel::backend::Backend;
+use ::diesel::deserialize::{self, FromSql, FromSqlRow};
+use ::diesel::expression::AsExpression;
+use ::diesel::query_builder::bind_collector::RawBytesBindCollector;
+use ::diesel::serialize::{self, IsNull, Output, ToSql};
+use ::diesel::sql_types::Text;
+
+use crate::NorwegianTin;
+
+// `NorwegianTin` lives in the crate root, where `diesel` names this module rather
+// than the diesel crate, so the derives are applied through a foreign proxy.
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive)]
+#[diesel(sql_type = Text)]
+#[allow(dead_code)]
+struct NorwegianTinProxy(NorwegianTin);
+
+/// Writes the digit string of the number, as stored in a `Text` column.
+impl<DB> ToSql<Text, DB> for NorwegianTin
+where
+    for<'c> DB: Backend<BindCollector<'c> = RawBytesBindCollector<DB>>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        let value: String = (*self).into();
+        out.write_all(value.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+/// Parses the loaded text, so invalid rows fail to load.
+impl<DB> FromSql<Text, DB> for NorwegianTin
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let value = <String as FromSql<Text, DB>>::from_sql(bytes)?;
+        Ok(NorwegianTin::parse(&value)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::diesel::mysql::{Mysql, MysqlType, MysqlValue};
+    use ::diesel::query_builder::bind_collector::BindCollector;
+
+    fn bind(tin: &NorwegianTin) -> Vec<Option<Vec<u8>>> {
+        let mut collector = RawBytesBindCollector::<Mysql>::new();
+        collector
+            .push_bound_value::<Text, NorwegianTin>(tin, &mut ())
+            .unwrap();
+        collector.binds
+    }
+
+    fn load(raw: &[u8]) -> deserialize::Result<NorwegianTin> {
+        <NorwegianTin as FromSql<Text, Mysql>>::from_sql(MysqlValue::new(raw, MysqlType::String))
+    }
+
+    #[test]
+    fn test_to_sql_round_trip() {
+        // A D-number and a DUF number, whose digits are not a plain integer
+        for value in ["70887100797", "201234567800"] {
+            let tin = NorwegianTin::parse(value).unwrap();
+            let binds = bind(&tin);
+            assert_eq!(binds, vec![Some(value.as_bytes().to_vec())]);
+            assert_eq!(load(binds[0].as_deref().unwrap()).unwrap(), tin);
+        }
+    }
+
+    #[test]
+    fn test_from_sql_invalid() {
+        let err = load(b"12345678901").unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<crate::NorwegianTinError>(),
+            Some(&crate::NorwegianTinError::InvalidChecksum {
+                position: 9,
+                expected: Some(1),
+                found: 0
+            })
+        );
+        assert!(load(b"\xff\xfe").is_err());
+        assert!(<NorwegianTin as FromSql<Text, Mysql>>::from_nullable_sql(None).is_err());
+    }
+}
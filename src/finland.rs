@@ -0,0 +1,220 @@
+use std::str::FromStr;
+
+use crate::{Date, NorwegianTinError, Tin};
+
+const HETU_LENGTH: usize = 11;
+const CHECK_CHARACTERS: &[u8; 31] = b"0123456789ABCDEFHJKLMNPRSTUVWXY";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum HetuKind {
+    Permanent,
+    /// Temporary code, with an individual number of 900–999.
+    Temporary,
+}
+
+/// A Finnish personal identity code (henkilötunnus).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Hetu {
+    value: [u8; HETU_LENGTH],
+}
+
+impl Hetu {
+    /// Parses "DDMMYYCZZZQ", where C is the century sign (including the
+    /// signs introduced in 2023) and Q the check character.
+    pub fn parse(s: &str) -> Result<Hetu, NorwegianTinError> {
+        let bytes = s.as_bytes();
+        if bytes.len() != HETU_LENGTH {
+            return Err(NorwegianTinError::InvalidLength { found: bytes.len() });
+        }
+        let mut value = [0u8; HETU_LENGTH];
+        value.copy_from_slice(bytes);
+        value[10] = value[10].to_ascii_uppercase();
+        value[6] = value[6].to_ascii_uppercase();
+
+        if let Some(position) = (0..6).chain(7..10).find(|&i| !bytes[i].is_ascii_digit()) {
+            return Err(NorwegianTinError::NonNumericValue { position });
+        }
+        let hetu = Hetu { value };
+        let century = hetu
+            .century()
+            .ok_or(NorwegianTinError::NonNumericValue { position: 6 })?;
+
+        let number = bytes[0..6]
+            .iter()
+            .chain(bytes[7..10].iter())
+            .fold(0u32, |acc, &b| acc * 10 + (b - b'0') as u32);
+        // Check characters are reported by their index in CHECK_CHARACTERS
+        let expected = (number % 31) as u8;
+        if CHECK_CHARACTERS[expected as usize] != value[10] {
+            let found = CHECK_CHARACTERS
+                .iter()
+                .position(|&c| c == value[10])
+                .ok_or(NorwegianTinError::NonNumericValue { position: 10 })?;
+            return Err(NorwegianTinError::InvalidChecksum {
+                position: 10,
+                expected: Some(expected),
+                found: found as u8,
+            });
+        }
+
+        let day = hetu.digit(0) * 10 + hetu.digit(1);
+        let month = hetu.digit(2) * 10 + hetu.digit(3);
+        Date::new(
+            century + hetu.digit(4) as u16 * 10 + hetu.digit(5) as u16,
+            month,
+            day,
+        )?;
+        Ok(hetu)
+    }
+
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    pub fn birth_year(&self) -> u16 {
+        self.century().unwrap_or_default() + self.digit(4) as u16 * 10 + self.digit(5) as u16
+    }
+
+    /// The individual number ZZZ. Numbers 900–999 are used for temporary codes.
+    pub fn individual_number(&self) -> u16 {
+        self.value[7..10]
+            .iter()
+            .fold(0, |acc, &b| acc * 10 + (b - b'0') as u16)
+    }
+
+    pub fn is_temporary(&self) -> bool {
+        self.individual_number() >= 900
+    }
+
+    pub fn kind(&self) -> HetuKind {
+        if self.is_temporary() {
+            HetuKind::Temporary
+        } else {
+            HetuKind::Permanent
+        }
+    }
+
+    pub fn birth_date(&self) -> Date {
+        Date {
+            year: self.birth_year(),
+            month: self.digit(2) * 10 + self.digit(3),
+            day: self.digit(0) * 10 + self.digit(1),
+        }
+    }
+
+    fn digit(&self, i: usize) -> u8 {
+        self.value[i] - b'0'
+    }
+
+    fn century(&self) -> Option<u16> {
+        match self.value[6] {
+            b'+' => Some(1800),
+            b'-' | b'Y' | b'X' | b'W' | b'V' | b'U' => Some(1900),
+            b'A' | b'B' | b'C' | b'D' | b'E' | b'F' => Some(2000),
+            _ => None,
+        }
+    }
+}
+
+impl Tin for Hetu {
+    type Kind = HetuKind;
+
+    fn kind(&self) -> HetuKind {
+        Hetu::kind(self)
+    }
+
+    fn canonical_string(&self) -> String {
+        (*self).into()
+    }
+
+    fn birth_date(&self) -> Option<Date> {
+        Some(Hetu::birth_date(self))
+    }
+}
+
+/// Returns whether `s` is a valid personal identity code.
+pub fn is_valid(s: &str) -> bool {
+    Hetu::parse(s).is_ok()
+}
+
+impl FromStr for Hetu {
+    type Err = NorwegianTinError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl From<Hetu> for String {
+    fn from(hetu: Hetu) -> String {
+        hetu.value.iter().map(|&b| b as char).collect()
+    }
+}
+
+impl AsRef<[u8]> for Hetu {
+    fn as_ref(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+impl std::fmt::Display for Hetu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s: String = (*self).into();
+        // Masking the individual number and check character for privacy
+        write!(f, "{}****", &s[0..7])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use crate::ErrorKind;
+
+    #[test]
+    fn test_valid_hetu() {
+        let codes = vec![
+            ("131052-308T", 1952),
+            ("131052Y308T", 1952),
+            ("131052X308T", 1952),
+            ("010594Y9995", 1994),
+            ("290200A002C", 2000),
+            ("290200F002C", 2000),
+            ("010101+123N", 1801),
+            ("131052-308t", 1952),
+        ];
+        for (code, year) in codes {
+            let hetu = Hetu::parse(code).unwrap();
+            assert_eq!(hetu.birth_year(), year);
+            assert_eq!(hetu.birth_date().year(), year);
+            assert!(is_valid(code));
+        }
+        assert!(Hetu::parse("010594Y9995").unwrap().is_temporary());
+        assert!(!Hetu::parse("131052-308T").unwrap().is_temporary());
+    }
+
+    #[test]
+    fn test_invalid_hetu() {
+        assert_eq!(
+            Hetu::parse("131052-308U").unwrap_err().kind(),
+            ErrorKind::InvalidChecksum
+        );
+        // 1900 was not a leap year
+        assert_eq!(
+            Hetu::parse("290200-002C").unwrap_err().kind(),
+            ErrorKind::InvalidDate
+        );
+        assert_eq!(
+            Hetu::parse("131052G308T").unwrap_err().kind(),
+            ErrorKind::NonNumericValue
+        );
+        assert_eq!(
+            Hetu::parse("131052-308").unwrap_err().kind(),
+            ErrorKind::InvalidLength
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let hetu = Hetu::parse("131052-308T").unwrap();
+        assert_eq!(format!("{}", hetu), "131052-****");
+    }
+}
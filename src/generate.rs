@@ -0,0 +1,828 @@
+use std::io;
+use std::ops::RangeInclusive;
+
+use crate::{
+    Accept, Date, DufNumber, Gender, NorwegianTin, OrgNumber, PersonKind, PersonNumber, TinKind,
+    DUF_LENGTH, ORG_LENGTH, SEQUENCE_DUF_CHECKSUM_DIGITS, SEQUENCE_FIRST_CHECKSUM_DIGITS,
+    SEQUENCE_ORG_CHECKSUM_DIGITS, SEQUENCE_SECOND_CHECKSUM_DIGITS, TIN_LENGTH,
+};
+
+/// The kind of number to generate. Person numbers are born between 1900 and
+/// 2039, the years the individual number can tell apart.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TinSpec {
+    kind: TinKind,
+    person_kind: PersonKind,
+}
+
+impl TinSpec {
+    pub fn new(kind: TinKind) -> Self {
+        TinSpec {
+            kind,
+            person_kind: PersonKind::Normal,
+        }
+    }
+
+    /// The category of person numbers, e.g. `Synthetic` for Tenor style test
+    /// numbers. Organisation numbers are only `Normal` or `Synthetic`, and
+    /// DUF numbers ignore it.
+    pub fn person_kind(mut self, kind: PersonKind) -> Self {
+        self.person_kind = kind;
+        self
+    }
+}
+
+/// Normal F-numbers.
+impl Default for TinSpec {
+    fn default() -> Self {
+        TinSpec::new(TinKind::FNumber)
+    }
+}
+
+/// Builds a valid number of `spec`, drawing from `below`, which returns a
+/// uniform value in `0..n` for each `n` it is called with. This fits any
+/// source of randomness; the `rand` feature wires it up to `rand::Rng`.
+pub fn generate(spec: &TinSpec, below: &mut dyn FnMut(u32) -> u32) -> NorwegianTin {
+    match spec.kind {
+        TinKind::FNumber | TinKind::DNumber => generate_person(spec, below),
+        TinKind::OrgNumber => generate_org(spec, below),
+        TinKind::DufNumber => generate_duf(below),
+    }
+}
+
+/// Shape of a generated population of person numbers: which birth years are
+/// common, the share of women and the share of D-numbers. Shares are kept to
+/// a tenth of a percent.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Profile {
+    birth_years: Vec<(RangeInclusive<u16>, u32)>,
+    female_per_mille: u32,
+    d_number_per_mille: u32,
+    person_kind: PersonKind,
+}
+
+impl Profile {
+    /// Birth years spread evenly over 1900–2039, half women and only
+    /// F-numbers.
+    pub fn new() -> Self {
+        Profile {
+            birth_years: vec![(1900..=2039, 1)],
+            female_per_mille: 500,
+            d_number_per_mille: 0,
+            person_kind: PersonKind::Normal,
+        }
+    }
+
+    /// Roughly the Norwegian population of the mid 2020s: the age pyramid by
+    /// decade of birth, an even gender split and about one in twenty people
+    /// using a D-number.
+    pub fn norway() -> Self {
+        Profile::new()
+            .birth_years(vec![
+                (1925..=1934, 1),
+                (1935..=1944, 4),
+                (1945..=1954, 9),
+                (1955..=1964, 11),
+                (1965..=1974, 13),
+                (1975..=1984, 13),
+                (1985..=1994, 14),
+                (1995..=2004, 13),
+                (2005..=2014, 12),
+                (2015..=2024, 11),
+            ])
+            .d_number_share(0.05)
+    }
+
+    /// Birth year ranges with their relative weights, replacing the current
+    /// ones.
+    ///
+    /// Panics if a range is empty, reaches outside 1900–2039 or every weight
+    /// is zero.
+    pub fn birth_years(mut self, years: Vec<(RangeInclusive<u16>, u32)>) -> Self {
+        for (range, _) in &years {
+            assert!(
+                range.start() <= range.end() && *range.start() >= 1900 && *range.end() <= 2039,
+                "birth years must lie within 1900–2039"
+            );
+        }
+        assert!(
+            years.iter().any(|(_, weight)| *weight > 0),
+            "at least one birth year range needs a weight"
+        );
+        self.birth_years = years;
+        self
+    }
+
+    /// Share of women, from 0.0 to 1.0.
+    pub fn female_share(mut self, share: f64) -> Self {
+        self.female_per_mille = per_mille(share);
+        self
+    }
+
+    /// Share of D-numbers, from 0.0 to 1.0.
+    pub fn d_number_share(mut self, share: f64) -> Self {
+        self.d_number_per_mille = per_mille(share);
+        self
+    }
+
+    /// The category of every number, e.g. `Synthetic` for demo datasets.
+    pub fn person_kind(mut self, kind: PersonKind) -> Self {
+        self.person_kind = kind;
+        self
+    }
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn per_mille(share: f64) -> u32 {
+    (share.clamp(0.0, 1.0) * 1000.0).round() as u32
+}
+
+/// Builds a valid person number drawn from `profile`, with `below` as for
+/// `generate`.
+pub fn generate_from_profile(profile: &Profile, below: &mut dyn FnMut(u32) -> u32) -> NorwegianTin {
+    let total: u32 = profile.birth_years.iter().map(|(_, weight)| weight).sum();
+    let mut pick = below(total);
+    let mut years = &profile.birth_years[0].0;
+    for (range, weight) in &profile.birth_years {
+        if pick < *weight {
+            years = range;
+            break;
+        }
+        pick -= weight;
+    }
+    let year = years.start() + below((years.end() - years.start()) as u32 + 1) as u16;
+    let d_number = below(1000) < profile.d_number_per_mille;
+    let gender = if below(1000) < profile.female_per_mille {
+        Gender::Female
+    } else {
+        Gender::Male
+    };
+    person_number(d_number, profile.person_kind, year, Some(gender), below)
+}
+
+/// The single way an input from `generate_invalid` is broken.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum InvalidReason {
+    /// A person number where only the first check digit is wrong; the second
+    /// one matches the digits before it.
+    FirstChecksum,
+    /// A person number with valid check digits and a date that does not
+    /// exist, e.g. 31 April.
+    ImpossibleDate,
+    /// A valid person number with two different adjacent digits swapped.
+    TransposedDigits,
+    /// A valid person number with one digit dropped.
+    WrongLength,
+}
+
+/// An input that fails to parse for `reason` only, with `below` as for
+/// `generate`.
+pub fn generate_invalid(reason: InvalidReason, below: &mut dyn FnMut(u32) -> u32) -> String {
+    let digits = match reason {
+        InvalidReason::FirstChecksum => loop {
+            let mut value = valid_person_digits(below);
+            // Numbers issued from 2032 accept a first check digit up to three
+            // above the classic one, so move it further than that
+            let shift = 4 + below(6) as u8;
+            value[9] = (value[9] + shift) % 11;
+            if value[9] == 10 {
+                continue;
+            }
+            value[10] = 0;
+            if let Some(digit) = check_digit(SEQUENCE_SECOND_CHECKSUM_DIGITS, &value) {
+                value[10] = digit;
+                break value.to_vec();
+            }
+        },
+        InvalidReason::ImpossibleDate => {
+            let year = 1900 + below(140) as u16;
+            let month = [2, 4, 6, 9, 11][below(5) as usize];
+            // February gets 30 or 31, so leap years do not matter
+            let day = match month {
+                2 => 30 + below(2) as u8,
+                _ => 31,
+            };
+            person_digits(day, month, year, None, below).to_vec()
+        }
+        InvalidReason::TransposedDigits => loop {
+            let mut value = valid_person_digits(below).to_vec();
+            let i = below(TIN_LENGTH as u32 - 1) as usize;
+            if value[i] == value[i + 1] {
+                continue;
+            }
+            value.swap(i, i + 1);
+            if !is_valid_digits(&value) {
+                break value;
+            }
+        },
+        InvalidReason::WrongLength => {
+            let mut value = valid_person_digits(below).to_vec();
+            value.remove(below(TIN_LENGTH as u32) as usize);
+            value
+        }
+    };
+    digits.iter().map(|&d| (d + b'0') as char).collect()
+}
+
+fn valid_person_digits(below: &mut dyn FnMut(u32) -> u32) -> [u8; TIN_LENGTH] {
+    match generate_person(&TinSpec::default(), below) {
+        NorwegianTin::FNumber(person) => person.value,
+        _ => unreachable!("the default spec gives F-numbers"),
+    }
+}
+
+fn is_valid_digits(digits: &[u8]) -> bool {
+    let s: String = digits.iter().map(|&d| (d + b'0') as char).collect();
+    NorwegianTin::parse(&s).is_ok()
+}
+
+/// Reproducible stream of numbers from a seed. The sequence only depends on
+/// the seed and the spec, not on the platform or the version of any random
+/// number crate, so a seed committed next to a test suite keeps giving the
+/// same fixtures.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    state: u64,
+    spec: TinSpec,
+    profile: Option<Profile>,
+}
+
+impl Generator {
+    /// A generator of normal F-numbers, like `TinSpec::default()`.
+    pub fn new(seed: u64) -> Self {
+        Generator {
+            state: seed,
+            spec: TinSpec::default(),
+            profile: None,
+        }
+    }
+
+    pub fn spec(mut self, spec: TinSpec) -> Self {
+        self.spec = spec;
+        self.profile = None;
+        self
+    }
+
+    /// Iterates person numbers drawn from `profile` instead of a spec.
+    pub fn profile(mut self, profile: Profile) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+
+    /// The next person number drawn from `profile`, advancing the same
+    /// sequence.
+    pub fn next_from(&mut self, profile: &Profile) -> NorwegianTin {
+        generate_from_profile(profile, &mut |n| self.below(n))
+    }
+
+    /// The next number of a different spec, advancing the same sequence.
+    pub fn next_with(&mut self, spec: &TinSpec) -> NorwegianTin {
+        generate(spec, &mut |n| self.below(n))
+    }
+
+    /// The next input broken for `reason`, advancing the same sequence.
+    pub fn next_invalid(&mut self, reason: InvalidReason) -> String {
+        generate_invalid(reason, &mut |n| self.below(n))
+    }
+
+    /// SplitMix64, scaled to `0..n` by multiplying with the upper half.
+    fn below(&mut self, n: u32) -> u32 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut x = self.state;
+        x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        x ^= x >> 31;
+        (((x >> 32) * n as u64) >> 32) as u32
+    }
+}
+
+impl Iterator for Generator {
+    type Item = NorwegianTin;
+
+    fn next(&mut self) -> Option<NorwegianTin> {
+        match self.profile.take() {
+            Some(profile) => {
+                let tin = self.next_from(&profile);
+                self.profile = Some(profile);
+                Some(tin)
+            }
+            None => {
+                let spec = self.spec;
+                Some(self.next_with(&spec))
+            }
+        }
+    }
+}
+
+/// What `export_fixtures` writes: `count` numbers from a `Generator` seeded
+/// with `seed`, drawn from a spec or a profile.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FixtureSpec {
+    seed: u64,
+    count: usize,
+    spec: TinSpec,
+    profile: Option<Profile>,
+}
+
+impl FixtureSpec {
+    /// `count` normal F-numbers, like `TinSpec::default()`.
+    pub fn new(seed: u64, count: usize) -> Self {
+        FixtureSpec {
+            seed,
+            count,
+            spec: TinSpec::default(),
+            profile: None,
+        }
+    }
+
+    pub fn spec(mut self, spec: TinSpec) -> Self {
+        self.spec = spec;
+        self.profile = None;
+        self
+    }
+
+    pub fn profile(mut self, profile: Profile) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FixtureFormat {
+    /// An array of objects with the fields `tin`, `kind`, `person_kind`,
+    /// `birth_date` and `gender`, the last three `null` when not known.
+    Json,
+    /// A header row and the same columns as `Json`, empty when not known.
+    Csv,
+}
+
+/// Writes the numbers of `spec` with their metadata to `writer`, so test
+/// environments outside Rust can be seeded from the same generator. Birth
+/// dates are `YYYY-MM-DD`, and kinds and genders are snake case names such
+/// as `d_number` and `female`.
+pub fn export_fixtures<W: io::Write>(
+    spec: &FixtureSpec,
+    format: FixtureFormat,
+    mut writer: W,
+) -> io::Result<()> {
+    let mut generator = Generator::new(spec.seed).spec(spec.spec);
+    if let Some(profile) = &spec.profile {
+        generator = generator.profile(profile.clone());
+    }
+    match format {
+        FixtureFormat::Json => writer.write_all(b"[")?,
+        FixtureFormat::Csv => writer.write_all(b"tin,kind,person_kind,birth_date,gender\n")?,
+    }
+    for (i, tin) in generator.take(spec.count).enumerate() {
+        let kind = match tin.tin_kind() {
+            TinKind::FNumber => "f_number",
+            TinKind::DNumber => "d_number",
+            TinKind::OrgNumber => "org_number",
+            TinKind::DufNumber => "duf_number",
+        };
+        let person_kind = tin.person_kind().map(|kind| match kind {
+            PersonKind::Normal => "normal",
+            PersonKind::HNumber => "h_number",
+            PersonKind::Anonymous => "anonymous",
+            PersonKind::Synthetic => "synthetic",
+        });
+        let birth_date = tin.birth_date().map(|date| date.to_string());
+        let gender = tin.gender().map(|gender| match gender {
+            Gender::Female => "female",
+            Gender::Male => "male",
+        });
+        match format {
+            FixtureFormat::Json => {
+                let quoted = |value: Option<&str>| match value {
+                    Some(value) => format!("\"{}\"", value),
+                    None => "null".to_string(),
+                };
+                write!(
+                    writer,
+                    "{}\n  {{\"tin\": \"{}\", \"kind\": \"{}\", \"person_kind\": {}, \"birth_date\": {}, \"gender\": {}}}",
+                    if i == 0 { "" } else { "," },
+                    tin.canonical_string(),
+                    kind,
+                    quoted(person_kind),
+                    quoted(birth_date.as_deref()),
+                    quoted(gender),
+                )?;
+            }
+            FixtureFormat::Csv => writeln!(
+                writer,
+                "{},{},{},{},{}",
+                tin.canonical_string(),
+                kind,
+                person_kind.unwrap_or(""),
+                birth_date.as_deref().unwrap_or(""),
+                gender.unwrap_or(""),
+            )?,
+        }
+    }
+    if format == FixtureFormat::Json {
+        writer.write_all(b"\n]\n")?;
+    }
+    writer.flush()
+}
+
+/// Every valid normal person number born from `from` to `to`, both
+/// included, of the F- and D-number kinds in `accept`. Numbers come date by
+/// date, F-numbers before D-numbers, in order of individual number.
+///
+/// Only the classic scheme is enumerated: from 2032 each individual number can
+/// also take up to three more first check digits, see `Scheme::New`. Dates
+/// outside 1854–2039 have no individual number series and yield nothing.
+pub fn iter_range(from: Date, to: Date, accept: Accept) -> impl Iterator<Item = NorwegianTin> {
+    let variants: Vec<bool> = [(TinKind::FNumber, false), (TinKind::DNumber, true)]
+        .iter()
+        .filter(|(kind, _)| accept.contains(*kind))
+        .map(|&(_, d_number)| d_number)
+        .collect();
+    let dates = std::iter::successors(Some(from), |date| next_day(*date))
+        .take_while(move |date| *date <= to);
+    dates.flat_map(move |date| {
+        variants.clone().into_iter().flat_map(move |d_number| {
+            individual_series(date.year)
+                .iter()
+                .cloned()
+                .flatten()
+                .filter_map(move |individual| {
+                    let day = date.day + if d_number { 40 } else { 0 };
+                    let mut value = [
+                        day / 10,
+                        day % 10,
+                        date.month / 10,
+                        date.month % 10,
+                        (date.year % 100 / 10) as u8,
+                        (date.year % 10) as u8,
+                        (individual / 100) as u8,
+                        (individual / 10 % 10) as u8,
+                        (individual % 10) as u8,
+                        0,
+                        0,
+                    ];
+                    value[9] = check_digit(SEQUENCE_FIRST_CHECKSUM_DIGITS, &value)?;
+                    value[10] = check_digit(SEQUENCE_SECOND_CHECKSUM_DIGITS, &value)?;
+                    let person = PersonNumber {
+                        kind: PersonKind::Normal,
+                        value,
+                    };
+                    Some(if d_number {
+                        NorwegianTin::DNumber(person)
+                    } else {
+                        NorwegianTin::FNumber(person)
+                    })
+                })
+        })
+    })
+}
+
+/// The individual numbers that resolve to the century of `year`.
+fn individual_series(year: u16) -> &'static [RangeInclusive<u16>] {
+    match year {
+        1854..=1899 => &[500..=749],
+        1900..=1939 => &[0..=499],
+        1940..=1999 => &[0..=499, 900..=999],
+        2000..=2039 => &[500..=999],
+        _ => &[],
+    }
+}
+
+fn next_day(date: Date) -> Option<Date> {
+    let days = Date::days_in_month(date.year, date.month)?;
+    match (date.day < days, date.month < 12) {
+        (true, _) => Date::new(date.year, date.month, date.day + 1).ok(),
+        (false, true) => Date::new(date.year, date.month + 1, 1).ok(),
+        (false, false) => Date::new(date.year.checked_add(1)?, 1, 1).ok(),
+    }
+}
+
+fn generate_person(spec: &TinSpec, below: &mut dyn FnMut(u32) -> u32) -> NorwegianTin {
+    let year = 1900 + below(140) as u16;
+    let d_number = spec.kind == TinKind::DNumber;
+    person_number(d_number, spec.person_kind, year, None, below)
+}
+
+/// A person number born in `year`. The gender is set through the parity of
+/// the individual number, which every series lets flip.
+fn person_number(
+    d_number: bool,
+    person_kind: PersonKind,
+    year: u16,
+    gender: Option<Gender>,
+    below: &mut dyn FnMut(u32) -> u32,
+) -> NorwegianTin {
+    let month = 1 + below(12) as u8;
+    let days = Date::days_in_month(year, month).expect("month in range");
+    let day = 1 + below(days as u32) as u8;
+    let day_offset = if d_number { 40 } else { 0 };
+    let month_offset = match person_kind {
+        PersonKind::Normal => 0,
+        PersonKind::HNumber => 40,
+        PersonKind::Anonymous => 60,
+        PersonKind::Synthetic => 80,
+    };
+    let value = person_digits(day + day_offset, month + month_offset, year, gender, below);
+    let person = PersonNumber {
+        kind: person_kind,
+        value,
+    };
+    if d_number {
+        NorwegianTin::DNumber(person)
+    } else {
+        NorwegianTin::FNumber(person)
+    }
+}
+
+/// The digits of a person number with the given day and month fields,
+/// completed with an individual number and check digits. The date is not
+/// checked.
+fn person_digits(
+    day_field: u8,
+    month_field: u8,
+    year: u16,
+    gender: Option<Gender>,
+    below: &mut dyn FnMut(u32) -> u32,
+) -> [u8; TIN_LENGTH] {
+    let year_field = (year % 100) as u8;
+    loop {
+        let individual = match (individual_number(year, below), gender) {
+            (n, Some(Gender::Female)) => n & !1,
+            (n, Some(Gender::Male)) => n | 1,
+            (n, None) => n,
+        };
+        let mut value = [
+            day_field / 10,
+            day_field % 10,
+            month_field / 10,
+            month_field % 10,
+            year_field / 10,
+            year_field % 10,
+            (individual / 100) as u8,
+            (individual / 10 % 10) as u8,
+            (individual % 10) as u8,
+            0,
+            0,
+        ];
+        // About one in six individual numbers has no valid check digit
+        match check_digit(SEQUENCE_FIRST_CHECKSUM_DIGITS, &value) {
+            Some(digit) => value[9] = digit,
+            None => continue,
+        }
+        match check_digit(SEQUENCE_SECOND_CHECKSUM_DIGITS, &value) {
+            Some(digit) => value[10] = digit,
+            None => continue,
+        }
+        return value;
+    }
+}
+
+/// An individual number in a series that resolves to the century of `year`.
+fn individual_number(year: u16, below: &mut dyn FnMut(u32) -> u32) -> u16 {
+    match year {
+        2000..=2039 => 500 + below(500) as u16,
+        // 900–999 are also used for 1940–1999
+        1940..=1999 => match below(600) as u16 {
+            n @ 0..=499 => n,
+            n => n + 400,
+        },
+        _ => below(500) as u16,
+    }
+}
+
+/// The digit completing `value` so its weighted sum is divisible by 11,
+/// where the digit itself has weight 1 and is zero in `value`.
+pub(crate) fn check_digit(weights: &[u32; TIN_LENGTH], value: &[u8; TIN_LENGTH]) -> Option<u8> {
+    let sum: u32 = weights
+        .iter()
+        .zip(value.iter())
+        .map(|(&w, &d)| w * d as u32)
+        .sum();
+    match (11 - sum % 11) % 11 {
+        10 => None,
+        digit => Some(digit as u8),
+    }
+}
+
+fn generate_org(spec: &TinSpec, below: &mut dyn FnMut(u32) -> u32) -> NorwegianTin {
+    // The enterprise series starts with 8 or 9, the synthetic one with 2 or 3
+    let first = match spec.person_kind {
+        PersonKind::Synthetic => 2,
+        _ => 8,
+    };
+    loop {
+        let mut digits = [0u8; ORG_LENGTH];
+        digits[0] = first + below(2) as u8;
+        for digit in digits[1..8].iter_mut() {
+            *digit = below(10) as u8;
+        }
+        let sum: u32 = SEQUENCE_ORG_CHECKSUM_DIGITS
+            .iter()
+            .zip(digits.iter())
+            .map(|(&w, &d)| w as u32 * d as u32)
+            .sum();
+        match (11 - sum % 11) % 11 {
+            10 => continue,
+            digit => digits[8] = digit as u8,
+        }
+        return NorwegianTin::OrgNumber(OrgNumber::new_unchecked(digits));
+    }
+}
+
+fn generate_duf(below: &mut dyn FnMut(u32) -> u32) -> NorwegianTin {
+    let mut value = [0u8; DUF_LENGTH];
+    let year = 1990 + below(40);
+    value[0] = (year / 1000) as u8;
+    value[1] = (year / 100 % 10) as u8;
+    value[2] = (year / 10 % 10) as u8;
+    value[3] = (year % 10) as u8;
+    for digit in value[4..10].iter_mut() {
+        *digit = below(10) as u8;
+    }
+    let sum: u32 = SEQUENCE_DUF_CHECKSUM_DIGITS
+        .iter()
+        .zip(value.iter())
+        .map(|(&w, &d)| w as u32 * d as u32)
+        .sum();
+    let remainder = (sum % 11) as u8;
+    value[10] = remainder / 10;
+    value[11] = remainder % 10;
+    NorwegianTin::DufNumber(DufNumber { value })
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+
+    /// A linear congruential generator, good enough to spread the values.
+    fn lcg(seed: u64) -> impl FnMut(u32) -> u32 {
+        let mut state = seed;
+        move |n| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            ((state >> 33) % n as u64) as u32
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        let mut below = lcg(7);
+        let specs = [
+            TinSpec::default(),
+            TinSpec::new(TinKind::DNumber),
+            TinSpec::new(TinKind::FNumber).person_kind(PersonKind::Synthetic),
+            TinSpec::new(TinKind::DNumber).person_kind(PersonKind::HNumber),
+            TinSpec::new(TinKind::FNumber).person_kind(PersonKind::Anonymous),
+            TinSpec::new(TinKind::OrgNumber),
+            TinSpec::new(TinKind::OrgNumber).person_kind(PersonKind::Synthetic),
+            TinSpec::new(TinKind::DufNumber),
+        ];
+        for spec in specs.iter() {
+            for _ in 0..500 {
+                let tin = generate(spec, &mut below);
+                let parsed = NorwegianTin::parse(&tin.canonical_string()).unwrap();
+                assert_eq!(parsed, tin);
+                assert_eq!(parsed.tin_kind(), spec.kind);
+                if spec.kind != TinKind::DufNumber {
+                    assert_eq!(parsed.kind(), spec.person_kind);
+                }
+                if parsed.as_person().is_some() {
+                    assert!(parsed.birth_date().is_some());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generator() {
+        let first: Vec<NorwegianTin> = Generator::new(2024).take(50).collect();
+        let second: Vec<NorwegianTin> = Generator::new(2024).take(50).collect();
+        assert_eq!(first, second);
+        assert_ne!(first, Generator::new(2025).take(50).collect::<Vec<_>>());
+        // Pinned, so a change to the sequence is noticed
+        assert_eq!(first[0].canonical_string(), "09028706925");
+
+        let spec = TinSpec::new(TinKind::OrgNumber);
+        let orgs: Vec<NorwegianTin> = Generator::new(1).spec(spec).take(20).collect();
+        assert!(orgs.iter().all(|tin| tin.is_org_number()));
+        let mut generator = Generator::new(1);
+        assert_eq!(generator.next_with(&spec), orgs[0]);
+    }
+
+    #[test]
+    fn test_profile() {
+        let profile = Profile::new()
+            .birth_years(vec![(1950..=1959, 3), (2000..=2009, 1)])
+            .female_share(0.8)
+            .d_number_share(0.25);
+        let tins: Vec<NorwegianTin> = Generator::new(9).profile(profile).take(4000).collect();
+        let count = |f: &dyn Fn(&NorwegianTin) -> bool| tins.iter().filter(|tin| f(tin)).count();
+        for tin in &tins {
+            assert_eq!(NorwegianTin::parse(&tin.canonical_string()), Ok(*tin));
+            let year = tin.birth_date().unwrap().year();
+            assert!((1950..=1959).contains(&year) || (2000..=2009).contains(&year));
+        }
+        let fifties = count(&|tin| tin.birth_date().unwrap().year() < 2000);
+        assert!((2850..3150).contains(&fifties), "{}", fifties);
+        let women = count(&|tin| tin.gender() == Some(Gender::Female));
+        assert!((3050..3350).contains(&women), "{}", women);
+        let d_numbers = count(&|tin| tin.is_d_number());
+        assert!((850..1150).contains(&d_numbers), "{}", d_numbers);
+
+        let norway: Vec<NorwegianTin> = Generator::new(9)
+            .profile(Profile::norway())
+            .take(200)
+            .collect();
+        assert!(norway
+            .iter()
+            .all(|tin| tin.birth_date().unwrap().year() >= 1925));
+    }
+
+    #[test]
+    fn test_generate_invalid() {
+        use crate::{ErrorKind, NorwegianTinError};
+
+        let mut generator = Generator::new(3);
+        for _ in 0..300 {
+            let input = generator.next_invalid(InvalidReason::FirstChecksum);
+            assert!(matches!(
+                NorwegianTin::parse(&input),
+                Err(NorwegianTinError::InvalidChecksum { position: 9, .. })
+            ));
+
+            let input = generator.next_invalid(InvalidReason::ImpossibleDate);
+            let error = NorwegianTin::parse(&input).unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::InvalidDate, "{}", input);
+
+            let input = generator.next_invalid(InvalidReason::TransposedDigits);
+            assert_eq!(input.len(), 11);
+            assert!(NorwegianTin::parse(&input).is_err());
+
+            let input = generator.next_invalid(InvalidReason::WrongLength);
+            assert_eq!(
+                NorwegianTin::parse(&input),
+                Err(NorwegianTinError::InvalidLength { found: 10 })
+            );
+        }
+    }
+
+    #[test]
+    fn test_export_fixtures() {
+        let spec = FixtureSpec::new(2024, 3);
+        let mut csv = Vec::new();
+        export_fixtures(&spec, FixtureFormat::Csv, &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "tin,kind,person_kind,birth_date,gender");
+        assert_eq!(lines[1], "09028706925,f_number,normal,1987-02-09,male");
+        assert_eq!(lines.len(), 4);
+
+        let mut json = Vec::new();
+        export_fixtures(&spec, FixtureFormat::Json, &mut json).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), 3);
+        assert_eq!(json[0]["tin"], "09028706925");
+        assert_eq!(json[0]["birth_date"], "1987-02-09");
+
+        let orgs = FixtureSpec::new(1, 2).spec(TinSpec::new(TinKind::OrgNumber));
+        let mut json = Vec::new();
+        export_fixtures(&orgs, FixtureFormat::Json, &mut json).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(json[1]["kind"], "org_number");
+        assert!(json[1]["gender"].is_null());
+    }
+
+    #[test]
+    fn test_iter_range() {
+        let day = Date::new(1990, 1, 1).unwrap();
+        let enumerated: Vec<NorwegianTin> = iter_range(day, day, Accept::PERSON).collect();
+        let mut expected = Vec::new();
+        for prefix in ["010190", "410190"] {
+            for rest in 0..100_000 {
+                if let Ok(tin) = NorwegianTin::parse(&format!("{}{:05}", prefix, rest)) {
+                    if tin.scheme() == Some(crate::Scheme::Classic) && tin.birth_date() == Some(day)
+                    {
+                        expected.push(tin);
+                    }
+                }
+            }
+        }
+        assert_eq!(enumerated, expected);
+
+        let from = Date::new(2023, 12, 30).unwrap();
+        let to = Date::new(2024, 1, 2).unwrap();
+        let tins: Vec<NorwegianTin> = iter_range(from, to, Accept::F_NUMBER).collect();
+        assert!(tins.iter().all(|tin| tin.is_f_number()));
+        let dates: Vec<Date> = tins.iter().map(|tin| tin.birth_date().unwrap()).collect();
+        assert_eq!(dates.first(), Some(&from));
+        assert_eq!(dates.last(), Some(&to));
+        assert_eq!(iter_range(to, from, Accept::PERSON).count(), 0);
+    }
+}
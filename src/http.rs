@@ -0,0 +1,104 @@
+use crate::{ErrorKind, NorwegianTinError};
+
+/// Recommended HTTP status code for responding to an invalid number. Every
+/// error is a well-formed request with an unacceptable value, so this is 422
+/// Unprocessable Entity, as used by the `axum` and `actix` responses.
+pub fn status_code(err: &NorwegianTinError) -> u16 {
+    match err.kind() {
+        ErrorKind::InvalidLength
+        | ErrorKind::NonNumericValue
+        | ErrorKind::InvalidChecksum
+        | ErrorKind::InvalidDate
+        | ErrorKind::WrongIdentifierType
+        | ErrorKind::InvalidPrefix
+        | ErrorKind::FutureBirthDate
+        | ErrorKind::NotAccepted => 422,
+    }
+}
+
+/// Stable, machine-readable code of an error for API responses. Unlike the
+/// `Display` output, these codes are kept when variants are renamed.
+pub fn error_code(err: &NorwegianTinError) -> &'static str {
+    match err.kind() {
+        ErrorKind::InvalidLength => "TIN_LENGTH",
+        ErrorKind::NonNumericValue => "TIN_NON_NUMERIC",
+        ErrorKind::InvalidChecksum => "TIN_CHECKSUM",
+        ErrorKind::InvalidDate => "TIN_DATE",
+        ErrorKind::WrongIdentifierType => "TIN_WRONG_TYPE",
+        ErrorKind::InvalidPrefix => "TIN_PREFIX",
+        ErrorKind::FutureBirthDate => "TIN_FUTURE_BIRTH_DATE",
+        ErrorKind::NotAccepted => "TIN_NOT_ACCEPTED",
+    }
+}
+
+/// JSON body of an error response, e.g.
+/// `{"error":"InvalidChecksum","code":"TIN_CHECKSUM"}`, as sent by the
+/// `actix`, `axum` and `warp` integrations.
+pub fn error_body(err: &NorwegianTinError) -> String {
+    error_body_with(err, "")
+}
+
+/// `error_body` with `members`, e.g. `,"field":"tin"`, appended to the object.
+pub(crate) fn error_body_with(err: &NorwegianTinError, members: &str) -> String {
+    // Error names and codes are plain identifiers, so they need no JSON escaping
+    format!(
+        r#"{{"error":"{}","code":"{}"{}}}"#,
+        err,
+        error_code(err),
+        members
+    )
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+
+    #[test]
+    fn test_error_mapping() {
+        let errors = vec![
+            (NorwegianTinError::InvalidLength { found: 3 }, "TIN_LENGTH"),
+            (
+                NorwegianTinError::NonNumericValue { position: 0 },
+                "TIN_NON_NUMERIC",
+            ),
+            (
+                NorwegianTinError::InvalidChecksum {
+                    position: 10,
+                    expected: Some(4),
+                    found: 5,
+                },
+                "TIN_CHECKSUM",
+            ),
+            (
+                NorwegianTinError::InvalidDate {
+                    year: 79,
+                    month: 13,
+                    day: 16,
+                },
+                "TIN_DATE",
+            ),
+            (NorwegianTinError::WrongIdentifierType, "TIN_WRONG_TYPE"),
+            (NorwegianTinError::InvalidPrefix, "TIN_PREFIX"),
+            (
+                NorwegianTinError::FutureBirthDate {
+                    birth_date: crate::Date::new(2030, 1, 1).unwrap(),
+                },
+                "TIN_FUTURE_BIRTH_DATE",
+            ),
+            (
+                NorwegianTinError::NotAccepted {
+                    found: crate::TinKind::OrgNumber,
+                },
+                "TIN_NOT_ACCEPTED",
+            ),
+        ];
+        for (err, code) in errors {
+            assert_eq!(error_code(&err), code);
+            assert_eq!(status_code(&err), 422);
+        }
+        assert_eq!(
+            error_body(&NorwegianTinError::InvalidPrefix),
+            r#"{"error":"InvalidPrefix","code":"TIN_PREFIX"}"#
+        );
+    }
+}
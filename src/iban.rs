@@ -0,0 +1,178 @@
+use std::str::FromStr;
+
+use crate::kontonummer::Kontonummer;
+use crate::NorwegianTinError;
+
+const IBAN_LENGTH: usize = 15;
+const COUNTRY_CODE: &str = "NO";
+// "NO" with letters converted to numbers (N = 23, O = 24) as required by ISO 13616
+const COUNTRY_CODE_DIGITS: &[u8; 4] = &[2, 3, 2, 4];
+
+/// A Norwegian IBAN: "NO", two check digits and the 11 digit account number.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Iban {
+    check_digits: u8,
+    account: Kontonummer,
+}
+
+impl Iban {
+    /// Parses a NO-prefixed IBAN in electronic ("NO9386011117947") or print
+    /// ("NO93 8601 1117 947") format, validating both the mod-97 check digits
+    /// and the domestic account check digit.
+    pub fn parse(s: &str) -> Result<Iban, NorwegianTinError> {
+        let compact: String = s.chars().filter(|&c| c != ' ').collect();
+        if compact.len() != IBAN_LENGTH {
+            return Err(NorwegianTinError::InvalidLength {
+                found: compact.len(),
+            });
+        }
+        if !compact.is_char_boundary(2) || !compact[0..2].eq_ignore_ascii_case(COUNTRY_CODE) {
+            return Err(NorwegianTinError::InvalidPrefix);
+        }
+        let check = &compact.as_bytes()[2..4];
+        if let Some(i) = check.iter().position(|b| !b.is_ascii_digit()) {
+            return Err(NorwegianTinError::NonNumericValue { position: 2 + i });
+        }
+        let check_digits = (check[0] - b'0') * 10 + (check[1] - b'0');
+        let account = Kontonummer::parse(&compact[4..]).map_err(|err| err.shifted(4))?;
+        let expected = Self::check_digits_for(&account);
+        if expected != check_digits {
+            return Err(NorwegianTinError::InvalidChecksum {
+                position: 2,
+                expected: Some(expected),
+                found: check_digits,
+            });
+        }
+        Ok(Iban {
+            check_digits,
+            account,
+        })
+    }
+
+    pub fn from_kontonummer(account: Kontonummer) -> Iban {
+        Iban {
+            check_digits: Self::check_digits_for(&account),
+            account,
+        }
+    }
+
+    pub fn check_digits(&self) -> u8 {
+        self.check_digits
+    }
+
+    pub fn kontonummer(&self) -> Kontonummer {
+        self.account
+    }
+
+    /// Formats the IBAN in print format, grouped in blocks of four.
+    pub fn formatted(&self) -> String {
+        let s: String = (*self).into();
+        let mut out = String::with_capacity(s.len() + 3);
+        for (i, c) in s.chars().enumerate() {
+            if i > 0 && i % 4 == 0 {
+                out.push(' ');
+            }
+            out.push(c);
+        }
+        out
+    }
+
+    fn check_digits_for(account: &Kontonummer) -> u8 {
+        let remainder = account
+            .value()
+            .iter()
+            .chain(COUNTRY_CODE_DIGITS.iter())
+            .chain([0, 0].iter())
+            .fold(0u32, |acc, &d| (acc * 10 + d as u32) % 97);
+        (98 - remainder) as u8
+    }
+}
+
+impl Kontonummer {
+    pub fn to_iban(&self) -> Iban {
+        Iban::from_kontonummer(*self)
+    }
+}
+
+/// Returns whether `s` is a valid Norwegian IBAN.
+pub fn is_valid(s: &str) -> bool {
+    Iban::parse(s).is_ok()
+}
+
+impl FromStr for Iban {
+    type Err = NorwegianTinError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl From<Iban> for String {
+    fn from(iban: Iban) -> String {
+        let account: String = iban.account.into();
+        format!("{}{:02}{}", COUNTRY_CODE, iban.check_digits, account)
+    }
+}
+
+impl From<Kontonummer> for Iban {
+    fn from(account: Kontonummer) -> Iban {
+        Iban::from_kontonummer(account)
+    }
+}
+
+impl std::fmt::Display for Iban {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.formatted())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use crate::ErrorKind;
+
+    #[test]
+    fn test_valid_iban() {
+        let ibans = vec!["NO9386011117947", "NO93 8601 1117 947", "no9386011117947"];
+        for iban in ibans {
+            let parsed = Iban::parse(iban).unwrap();
+            assert_eq!(parsed.check_digits(), 93);
+            assert!(is_valid(iban));
+        }
+    }
+
+    #[test]
+    fn test_invalid_iban() {
+        assert_eq!(
+            Iban::parse("NO9486011117947").unwrap_err().kind(),
+            ErrorKind::InvalidChecksum
+        );
+        // mod-97 fits, but the domestic check digit does not
+        assert_eq!(
+            Iban::parse("NO6686011117948").unwrap_err().kind(),
+            ErrorKind::InvalidChecksum
+        );
+        assert_eq!(
+            Iban::parse("SE9386011117947").unwrap_err(),
+            NorwegianTinError::InvalidPrefix
+        );
+        assert_eq!(
+            Iban::parse("NO938601111794").unwrap_err().kind(),
+            ErrorKind::InvalidLength
+        );
+        assert_eq!(
+            Iban::parse("NOx386011117947").unwrap_err().kind(),
+            ErrorKind::NonNumericValue
+        );
+    }
+
+    #[test]
+    fn test_from_kontonummer() {
+        let account = Kontonummer::parse("8601.11.17947").unwrap();
+        let iban = account.to_iban();
+        assert_eq!(iban.kontonummer(), account);
+        let s: String = iban.into();
+        assert_eq!(s, "NO9386011117947");
+        assert_eq!(format!("{}", iban), "NO93 8601 1117 947");
+        assert_eq!(Iban::parse(&s).unwrap(), iban);
+    }
+}
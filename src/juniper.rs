@@ -0,0 +1,112 @@
+use ::juniper::{graphql_scalar, GraphQLScalar};
+
+use crate::NorwegianTin;
+
+/// The `NorwegianTin` scalar: a string of the full digits, validated on input
+/// coercion. Fields that should not expose person
+/// numbers can use `MaskedTin` instead.
+#[graphql_scalar]
+#[graphql(
+    name = "NorwegianTin",
+    with = tin_scalar,
+    parse_token(String)
+)]
+type FullTin = NorwegianTin;
+
+mod tin_scalar {
+    use super::FullTin;
+
+    // The full digits, while `Display` masks person numbers
+    pub(super) fn to_output(v: &FullTin) -> String {
+        (*v).into()
+    }
+
+    pub(super) fn from_input(s: &str) -> Result<FullTin, Box<str>> {
+        FullTin::parse(s).map_err(|err| format!("Invalid `NorwegianTin`: {}", err).into())
+    }
+}
+
+/// The `MaskedNorwegianTin` scalar: validated on input like the
+/// `NorwegianTin` scalar, but output masked like `Display`, e.g.
+/// "160579*****". Organisation numbers are public, so they are output in
+/// full like the `Display` of `OrgNumber`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, GraphQLScalar)]
+#[graphql(
+    name = "MaskedNorwegianTin",
+    with = masked_scalar,
+    parse_token(String)
+)]
+pub struct MaskedTin(pub NorwegianTin);
+
+impl std::fmt::Display for MaskedTin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0.as_org() {
+            Some(org) => org.fmt(f),
+            None => self.0.fmt(f),
+        }
+    }
+}
+
+mod masked_scalar {
+    use super::{MaskedTin, NorwegianTin};
+
+    pub(super) fn to_output(v: &MaskedTin) -> String {
+        v.to_string()
+    }
+
+    pub(super) fn from_input(s: &str) -> Result<MaskedTin, Box<str>> {
+        NorwegianTin::parse(s)
+            .map(MaskedTin)
+            .map_err(|err| format!("Invalid `MaskedNorwegianTin`: {}", err).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use ::juniper::{
+        graphql_object, graphql_value, EmptyMutation, EmptySubscription, RootNode, Variables,
+    };
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn full(tin: NorwegianTin) -> NorwegianTin {
+            tin
+        }
+
+        fn masked(tin: NorwegianTin) -> MaskedTin {
+            MaskedTin(tin)
+        }
+    }
+
+    fn execute(query: &str) -> Result<::juniper::Value, String> {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        ::juniper::execute_sync(query, None, &schema, &Variables::new(), &())
+            .map(|(value, _)| value)
+            .map_err(|err| format!("{:?}", err))
+    }
+
+    #[test]
+    fn test_juniper_scalars() {
+        assert_eq!(
+            execute(r#"{ full(tin: "16057902284") masked(tin: "16057902284") }"#),
+            Ok(graphql_value!({
+                "full": "16057902284",
+                "masked": "160579*****",
+            }))
+        );
+        assert_eq!(
+            execute(r#"{ masked(tin: "974760673") }"#),
+            Ok(graphql_value!({ "masked": "974760673" }))
+        );
+        let err = execute(r#"{ full(tin: "16057902285") }"#).unwrap_err();
+        assert!(err.starts_with("ValidationError"));
+        assert!(err.contains(r#"for type \"NorwegianTin\""#));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::NorwegianTinError;
+
+const KID_MIN_LENGTH: usize = 2;
+const KID_MAX_LENGTH: usize = 25;
+const SEQUENCE_MOD11_WEIGHTS: &[u32; 6] = &[2, 3, 4, 5, 6, 7];
+
+/// The control digit algorithm used by a KID payment reference.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum KidAlgorithm {
+    /// Luhn control digit.
+    Mod10,
+    /// Weighted modulo 11 control digit, where `-` stands for 10.
+    Mod11,
+}
+
+/// A Norwegian KID payment reference (kundeidentifikasjonsnummer).
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Kid {
+    value: String,
+    algorithm: KidAlgorithm,
+}
+
+impl Kid {
+    /// Parses a KID of 2–25 characters whose last character is a control
+    /// digit computed with `algorithm`.
+    pub fn parse(s: &str, algorithm: KidAlgorithm) -> Result<Kid, NorwegianTinError> {
+        if s.len() < KID_MIN_LENGTH || s.len() > KID_MAX_LENGTH {
+            return Err(NorwegianTinError::InvalidLength { found: s.len() });
+        }
+        if let Some(position) = s.bytes().position(|b| !b.is_ascii()) {
+            return Err(NorwegianTinError::NonNumericValue { position });
+        }
+        let (base, control) = s.split_at(s.len() - 1);
+        let expected = control_digit(base, algorithm)?;
+        if expected != control.as_bytes()[0] as char {
+            return match control.as_bytes()[0] {
+                b @ b'0'..=b'9' | b @ b'-' => Err(NorwegianTinError::InvalidChecksum {
+                    position: base.len(),
+                    expected: Some(control_value(expected as u8)),
+                    found: control_value(b),
+                }),
+                _ => Err(NorwegianTinError::NonNumericValue {
+                    position: base.len(),
+                }),
+            };
+        }
+        Ok(Kid {
+            value: s.to_string(),
+            algorithm,
+        })
+    }
+
+    /// Builds a KID from `base` by appending the control digit computed with
+    /// `algorithm`.
+    pub fn generate(base: &str, algorithm: KidAlgorithm) -> Result<Kid, NorwegianTinError> {
+        if base.len() < KID_MIN_LENGTH - 1 || base.len() > KID_MAX_LENGTH - 1 {
+            return Err(NorwegianTinError::InvalidLength { found: base.len() });
+        }
+        let mut value = String::with_capacity(base.len() + 1);
+        value.push_str(base);
+        value.push(control_digit(base, algorithm)?);
+        Ok(Kid { value, algorithm })
+    }
+
+    pub fn algorithm(&self) -> KidAlgorithm {
+        self.algorithm
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+}
+
+/// Returns whether `s` is a valid KID for `algorithm`.
+pub fn is_valid(s: &str, algorithm: KidAlgorithm) -> bool {
+    Kid::parse(s, algorithm).is_ok()
+}
+
+/// Computes the control digit for `base`. MOD11 uses `-` when the remainder
+/// leaves a control value of 10.
+pub fn control_digit(base: &str, algorithm: KidAlgorithm) -> Result<char, NorwegianTinError> {
+    if let Some(position) = base.bytes().position(|b| !b.is_ascii_digit()) {
+        return Err(NorwegianTinError::NonNumericValue { position });
+    }
+    let digits = base.bytes().rev().map(|b| (b - b'0') as u32);
+    let control = match algorithm {
+        KidAlgorithm::Mod10 => {
+            let sum: u32 = digits
+                .enumerate()
+                .map(|(i, d)| match i % 2 {
+                    0 if d * 2 > 9 => d * 2 - 9,
+                    0 => d * 2,
+                    _ => d,
+                })
+                .sum();
+            (10 - sum % 10) % 10
+        }
+        KidAlgorithm::Mod11 => {
+            let sum: u32 = digits
+                .zip(SEQUENCE_MOD11_WEIGHTS.iter().cycle())
+                .map(|(d, &w)| d * w)
+                .sum();
+            match (11 - sum % 11) % 11 {
+                10 => return Ok('-'),
+                v => v,
+            }
+        }
+    };
+    Ok((control as u8 + b'0') as char)
+}
+
+/// Value of a control character in checksum errors, where `-` counts as 10.
+fn control_value(c: u8) -> u8 {
+    match c {
+        b'-' => 10,
+        d => d - b'0',
+    }
+}
+
+impl AsRef<str> for Kid {
+    fn as_ref(&self) -> &str {
+        &self.value
+    }
+}
+
+impl From<Kid> for String {
+    fn from(kid: Kid) -> String {
+        kid.value
+    }
+}
+
+impl std::fmt::Display for Kid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use crate::ErrorKind;
+
+    #[test]
+    fn test_mod10() {
+        let kids = vec!["1234567897", "79927398713", "00", "18"];
+        for kid in kids {
+            assert!(is_valid(kid, KidAlgorithm::Mod10));
+        }
+        assert_eq!(
+            Kid::parse("1234567890", KidAlgorithm::Mod10)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidChecksum
+        );
+    }
+
+    #[test]
+    fn test_mod11() {
+        let kids = vec!["12345678903", "0000000000", "1000005-"];
+        for kid in kids {
+            assert!(is_valid(kid, KidAlgorithm::Mod11));
+        }
+        assert_eq!(
+            Kid::parse("12345678904", KidAlgorithm::Mod11)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidChecksum
+        );
+        assert_eq!(
+            Kid::parse("1234567890-", KidAlgorithm::Mod10)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidChecksum
+        );
+    }
+
+    #[test]
+    fn test_invalid_kid() {
+        assert_eq!(
+            Kid::parse("1", KidAlgorithm::Mod10).unwrap_err().kind(),
+            ErrorKind::InvalidLength
+        );
+        assert_eq!(
+            Kid::parse("12345678901234567890123456", KidAlgorithm::Mod10)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidLength
+        );
+        assert_eq!(
+            Kid::parse("12a45", KidAlgorithm::Mod11).unwrap_err().kind(),
+            ErrorKind::NonNumericValue
+        );
+        assert_eq!(
+            Kid::parse("1234x", KidAlgorithm::Mod10).unwrap_err().kind(),
+            ErrorKind::NonNumericValue
+        );
+        assert_eq!(
+            Kid::parse("12ø", KidAlgorithm::Mod10).unwrap_err().kind(),
+            ErrorKind::NonNumericValue
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        let kid = Kid::generate("123456789", KidAlgorithm::Mod10).unwrap();
+        assert_eq!(kid.as_str(), "1234567897");
+        let kid = Kid::generate("1234567890", KidAlgorithm::Mod11).unwrap();
+        assert_eq!(kid.as_str(), "12345678903");
+        assert_eq!(control_digit("1000005", KidAlgorithm::Mod11).unwrap(), '-');
+        assert_eq!(
+            Kid::generate("", KidAlgorithm::Mod10).unwrap_err().kind(),
+            ErrorKind::InvalidLength
+        );
+    }
+}
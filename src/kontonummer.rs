@@ -0,0 +1,173 @@
+use std::str::FromStr;
+
+use crate::{NorwegianTin, NorwegianTinError};
+
+const ACCOUNT_LENGTH: usize = 11;
+const SEQUENCE_ACCOUNT_CHECKSUM_DIGITS: &[u8; 10] = &[5, 4, 3, 2, 7, 6, 5, 4, 3, 2];
+
+/// A Norwegian bank account number (kontonummer).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Kontonummer {
+    value: [u8; ACCOUNT_LENGTH],
+}
+
+impl Kontonummer {
+    /// Parses an account number, either as 11 digits or in the standard
+    /// "1234.56.78903" grouping (spaces are accepted as separators too).
+    pub fn parse(s: &str) -> Result<Kontonummer, NorwegianTinError> {
+        let mut digits = [0u8; ACCOUNT_LENGTH];
+        let mut len = 0;
+        for (i, &b) in s.as_bytes().iter().enumerate() {
+            match b {
+                b'.' | b' ' => continue,
+                b'0'..=b'9' => {
+                    if len == ACCOUNT_LENGTH {
+                        return Err(NorwegianTinError::InvalidLength {
+                            found: s.bytes().filter(u8::is_ascii_digit).count(),
+                        });
+                    }
+                    digits[len] = b - b'0';
+                    len += 1;
+                }
+                _ => return Err(NorwegianTinError::NonNumericValue { position: i }),
+            }
+        }
+        if len != ACCOUNT_LENGTH {
+            return Err(NorwegianTinError::InvalidLength { found: len });
+        }
+        Self::from_digits(digits)
+    }
+
+    pub fn from_digits(digits: [u8; ACCOUNT_LENGTH]) -> Result<Kontonummer, NorwegianTinError> {
+        if let Some(position) = digits.iter().position(|&d| d > 9) {
+            return Err(NorwegianTinError::NonNumericValue { position });
+        }
+        NorwegianTin::calculate_checksum(&digits[0..10], SEQUENCE_ACCOUNT_CHECKSUM_DIGITS, |r| {
+            match (11 - r) % 11 {
+                v if v == digits[10] => Ok(()),
+                v => Err(NorwegianTinError::InvalidChecksum {
+                    position: 10,
+                    expected: if v == 10 { None } else { Some(v) },
+                    found: digits[10],
+                }),
+            }
+        })?;
+        Ok(Kontonummer { value: digits })
+    }
+
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// The four digit bank registration number (registernummer).
+    pub fn registernummer(&self) -> u16 {
+        self.value[0..4]
+            .iter()
+            .fold(0, |acc, &d| acc * 10 + d as u16)
+    }
+
+    /// The two digit account group following the registernummer.
+    pub fn account_group(&self) -> u8 {
+        self.value[4] * 10 + self.value[5]
+    }
+
+    /// The four digit customer account number preceding the check digit.
+    pub fn customer_number(&self) -> u16 {
+        self.value[6..10]
+            .iter()
+            .fold(0, |acc, &d| acc * 10 + d as u16)
+    }
+
+    pub fn check_digit(&self) -> u8 {
+        self.value[10]
+    }
+
+    /// Formats the account number as "1234.56.78903".
+    pub fn formatted(&self) -> String {
+        let s: String = (*self).into();
+        format!("{}.{}.{}", &s[0..4], &s[4..6], &s[6..11])
+    }
+}
+
+/// Returns whether `s` is a valid account number.
+pub fn is_valid(s: &str) -> bool {
+    Kontonummer::parse(s).is_ok()
+}
+
+impl FromStr for Kontonummer {
+    type Err = NorwegianTinError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl From<Kontonummer> for String {
+    fn from(account: Kontonummer) -> String {
+        account.value.iter().map(|&d| (d + b'0') as char).collect()
+    }
+}
+
+impl AsRef<[u8]> for Kontonummer {
+    fn as_ref(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+impl std::fmt::Display for Kontonummer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.formatted())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use crate::ErrorKind;
+
+    #[test]
+    fn test_valid_account() {
+        let accounts = vec![
+            "86011117947",
+            "12345678903",
+            "1234.56.78903",
+            "9710 05 00004",
+        ];
+        for account in accounts {
+            assert!(Kontonummer::parse(account).is_ok());
+            assert!(is_valid(account));
+        }
+    }
+
+    #[test]
+    fn test_invalid_account() {
+        assert_eq!(
+            Kontonummer::parse("86011117948").unwrap_err().kind(),
+            ErrorKind::InvalidChecksum
+        );
+        assert_eq!(
+            Kontonummer::parse("1234.56.7890").unwrap_err().kind(),
+            ErrorKind::InvalidLength
+        );
+        assert_eq!(
+            Kontonummer::parse("123456789034").unwrap_err().kind(),
+            ErrorKind::InvalidLength
+        );
+        assert_eq!(
+            Kontonummer::parse("1234-56-78903").unwrap_err().kind(),
+            ErrorKind::NonNumericValue
+        );
+    }
+
+    #[test]
+    fn test_account_parts() {
+        let account = Kontonummer::parse("15032028109").unwrap();
+        assert_eq!(account.registernummer(), 1503);
+        assert_eq!(account.account_group(), 20);
+        assert_eq!(account.customer_number(), 2810);
+        assert_eq!(account.check_digit(), 9);
+        assert_eq!(account.formatted(), "1503.20.28109");
+        assert_eq!(format!("{}", account), "1503.20.28109");
+        let s: String = account.into();
+        assert_eq!(s, "15032028109");
+    }
+}
@@ -1,13 +1,99 @@
 use core::str;
 use std::str::FromStr;
 
-const SEQUENCE_FIRST_CHECKSUM_DIGITS: &'static [u8; 9] = &[3, 7, 6, 1, 8, 9, 4, 5, 2];
-const SEQUENCE_SECOND_CHECKSUM_DIGITS: &'static [u8; 10] = &[5, 4, 3, 2, 7, 6, 5, 4, 3, 2];
+#[cfg(feature = "actix")]
+pub mod actix;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "avro")]
+pub mod avro;
+#[cfg(feature = "axum")]
+pub mod axum;
+pub mod batch;
+#[cfg(feature = "bloom")]
+pub mod bloom;
+#[cfg(feature = "brreg")]
+pub mod brreg;
+#[cfg(feature = "bson")]
+pub mod bson;
+pub mod claims;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "defmt")]
+mod defmt_impl;
+#[cfg(feature = "denmark")]
+pub mod denmark;
+pub mod diagnose;
+#[cfg(feature = "diesel")]
+pub mod diesel;
+#[cfg(feature = "finland")]
+pub mod finland;
+pub mod generate;
+pub mod http;
+pub mod iban;
+#[cfg(feature = "juniper")]
+pub mod juniper;
+pub mod kid;
+pub mod kontonummer;
+pub mod local;
+pub mod messages;
+pub mod mutate;
+#[cfg(feature = "ndjson")]
+pub mod ndjson;
+pub mod ocr;
+pub mod partial;
+pub mod peppol;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+#[cfg(feature = "proto")]
+pub mod proto;
+#[cfg(feature = "rand")]
+mod rand_impl;
+#[cfg(feature = "redis")]
+pub mod redis;
+pub mod repair;
+#[cfg(feature = "rkyv")]
+mod rkyv_impl;
+#[cfg(feature = "rocket")]
+pub mod rocket;
+pub mod scan;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "serde")]
+pub mod serde_numeric;
+pub mod set;
+#[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
+mod simd;
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+pub mod suggest;
+#[cfg(feature = "sweden")]
+pub mod sweden;
+#[cfg(feature = "tenor")]
+pub mod tenor;
+#[cfg(feature = "tokio")]
+pub mod tokio;
+#[cfg(feature = "tower")]
+pub mod tower;
+#[cfg(feature = "warp")]
+pub mod warp;
+
+// Person number weights including the check digits themselves (weight 1), so each
+// weighted sum can be checked directly modulo 11 after a single pass over the digits
+const SEQUENCE_FIRST_CHECKSUM_DIGITS: &[u32; 11] = &[3, 7, 6, 1, 8, 9, 4, 5, 2, 1, 0];
+const SEQUENCE_SECOND_CHECKSUM_DIGITS: &[u32; 11] = &[5, 4, 3, 2, 7, 6, 5, 4, 3, 2, 1];
 const TIN_LENGTH: usize = 11;
 const ORG_LENGTH: usize = 9;
-const SEQUENCE_ORG_CHECKSUM_DIGITS: &'static [u8; 8] = &[3, 2, 7, 6, 5, 4, 3, 2];
+const SEQUENCE_ORG_CHECKSUM_DIGITS: &[u8; 8] = &[3, 2, 7, 6, 5, 4, 3, 2];
+const DUF_LENGTH: usize = 12;
+const SEQUENCE_DUF_CHECKSUM_DIGITS: &[u8; 10] = &[4, 6, 3, 2, 4, 6, 3, 2, 4, 6];
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize)
+)]
 #[non_exhaustive]
 pub enum PersonKind {
     Normal,
@@ -17,43 +103,512 @@ pub enum PersonKind {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize)
+)]
 pub struct PersonNumber {
     kind: PersonKind,
     value: [u8; TIN_LENGTH],
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize)
+)]
 pub struct OrgNumber {
     value: [u8; ORG_LENGTH],
 }
 
+/// A DUF number assigned by UDI, made up of the registration year, a
+/// six digit serial and two check digits.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize)
+)]
+pub struct DufNumber {
+    value: [u8; DUF_LENGTH],
+}
+
+/// A person number that is known to be an F-number, so function signatures
+/// can require one. Dereferences to the `PersonNumber`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct FNumber(PersonNumber);
+
+/// A person number that is known to be a D-number, so function signatures
+/// can require one. Dereferences to the `PersonNumber`.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DNumber(PersonNumber);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize)
+)]
 #[non_exhaustive]
 pub enum NorwegianTin {
     FNumber(PersonNumber),
     DNumber(PersonNumber),
     OrgNumber(OrgNumber),
+    DufNumber(DufNumber),
+}
+
+/// Legal gender, encoded in the parity of the last individual digit.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Gender {
+    Female,
+    Male,
+}
+
+/// Which kind of number a `NorwegianTin` is, independent of the `PersonKind`
+/// category of person numbers.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum TinKind {
+    FNumber,
+    DNumber,
+    OrgNumber,
+    DufNumber,
+}
+
+/// The structure a string has, as judged by `classify` without verifying
+/// check digits.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum TinShape {
+    /// 11 digits with a plausible day and month.
+    Person,
+    /// 9 digits.
+    Org,
+    /// 12 digits.
+    Duf,
+}
+
+/// How many digits an integer given to `NorwegianTin::from_number` is
+/// padded to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NumberWidth {
+    /// 11 digits.
+    Person,
+    /// 9 digits.
+    Org,
+    /// 12 digits.
+    Duf,
+}
+
+/// Whether a person number is an F-number or a D-number.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PersonVariant {
+    FNumber,
+    DNumber,
+}
+
+/// The two independent properties of a person number: the F- or D-number
+/// series, told by the day field, and the category, told by the month field.
+/// A D-number can be an H-number or synthetic at the same time.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PersonFlags {
+    pub variant: PersonVariant,
+    pub kind: PersonKind,
+}
+
+/// Every field of a person number, as returned by `NorwegianTin::analyze`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct ParsedPerson {
+    pub variant: PersonVariant,
+    pub kind: PersonKind,
+    pub scheme: Scheme,
+    /// Day of birth, without the D-number offset.
+    pub day: u8,
+    /// Month of birth, without the offset of H-, anonymous and synthetic numbers.
+    pub month: u8,
+    /// Two digit year of birth.
+    pub year: u8,
+    /// `None` when the individual number does not determine the century.
+    pub century: Option<u16>,
+    pub individual_number: u16,
+    /// `None` for new scheme numbers.
+    pub gender: Option<Gender>,
+    pub first_check_digit: u8,
+    pub second_check_digit: u8,
+    /// The number as plain digits.
+    pub canonical: String,
 }
 
-#[derive(Debug, PartialEq)]
+/// The rules a person number was issued under, told apart by the first check
+/// digit.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Scheme {
+    /// The first check digit follows the classic modulus 11 rule. Numbers issued
+    /// from 2032 can also end up here, by chance.
+    Classic,
+    /// The first check digit is one of the values only allowed from 2032, where
+    /// the individual number no longer encodes the century or gender.
+    New,
+}
+
+/// A calendar date, e.g. the birth date encoded in a person number.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Date, NorwegianTinError> {
+        match Self::days_in_month(year, month) {
+            Some(days) if day >= 1 && day <= days => Ok(Date { year, month, day }),
+            _ => Err(NorwegianTinError::InvalidDate { year, month, day }),
+        }
+    }
+
+    fn days_in_month(year: u16, month: u8) -> Option<u8> {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+            4 | 6 | 9 | 11 => Some(30),
+            2 if Self::is_leap_year(year) => Some(29),
+            2 => Some(28),
+            _ => None,
+        }
+    }
+
+    fn is_leap_year(year: u16) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    /// Today's date in UTC, from the system clock.
+    #[cfg(feature = "clock")]
+    pub fn today() -> Date {
+        let elapsed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default();
+        Self::from_days_since_epoch((elapsed.as_secs() / 86_400) as u32)
+    }
+
+    // Civil date of a day count since 1970-01-01, after Howard Hinnant's
+    // `civil_from_days` with eras of 400 years starting on March 1st
+    #[cfg(feature = "clock")]
+    fn from_days_since_epoch(days: u32) -> Date {
+        let z = days + 719_468;
+        let era = z / 146_097;
+        let day_of_era = z % 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = year_of_era + era * 400 + u32::from(month <= 2);
+        Date {
+            year: year as u16,
+            month,
+            day,
+        }
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Common interface for tax identification numbers, so application code can
+/// be written once and used for every supported market.
+pub trait Tin: std::fmt::Display {
+    type Kind: Copy + std::fmt::Debug;
+
+    fn kind(&self) -> Self::Kind;
+
+    /// The number as plain digits, without separators or masking.
+    fn canonical_string(&self) -> String;
+
+    /// The number with its individual digits masked, as shown by `Display`.
+    fn masked(&self) -> String {
+        self.to_string()
+    }
+
+    fn is_test_id(&self) -> bool {
+        false
+    }
+
+    /// The birth date, for identifiers that encode one.
+    fn birth_date(&self) -> Option<Date> {
+        None
+    }
+}
+
+/// Set of number kinds accepted by `NorwegianTin::parse_with`, combined with
+/// `|`, e.g. `Accept::F_NUMBER | Accept::D_NUMBER`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Accept(u8);
+
+impl Accept {
+    pub const F_NUMBER: Accept = Accept(1);
+    pub const D_NUMBER: Accept = Accept(1 << 1);
+    pub const ORG_NUMBER: Accept = Accept(1 << 2);
+    pub const DUF_NUMBER: Accept = Accept(1 << 3);
+    /// F- and D-numbers.
+    pub const PERSON: Accept = Accept(Self::F_NUMBER.0 | Self::D_NUMBER.0);
+    pub const ALL: Accept = Accept(Self::PERSON.0 | Self::ORG_NUMBER.0 | Self::DUF_NUMBER.0);
+
+    pub fn contains(self, kind: TinKind) -> bool {
+        let flag = match kind {
+            TinKind::FNumber => Self::F_NUMBER,
+            TinKind::DNumber => Self::D_NUMBER,
+            TinKind::OrgNumber => Self::ORG_NUMBER,
+            TinKind::DufNumber => Self::DUF_NUMBER,
+        };
+        self.0 & flag.0 != 0
+    }
+}
+
+impl Default for Accept {
+    fn default() -> Self {
+        Accept::ALL
+    }
+}
+
+impl std::ops::BitOr for Accept {
+    type Output = Accept;
+
+    fn bitor(self, other: Accept) -> Accept {
+        Accept(self.0 | other.0)
+    }
+}
+
+/// Optional, stricter rules applied by `NorwegianTin::parse_with_options`.
+/// Every rule is off by default, which matches `NorwegianTin::parse`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct ParseOptions {
+    strict_org_prefix: bool,
+    reject_born_after: Option<Date>,
+    accept: Accept,
+}
+
+impl ParseOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rejects organisation numbers that do not start with 8 or 9 with
+    /// `InvalidPrefix`.
+    pub fn strict_org_prefix(mut self, strict: bool) -> Self {
+        self.strict_org_prefix = strict;
+        self
+    }
+
+    /// Rejects person numbers whose birth date lies after `today` with
+    /// `FutureBirthDate`. Numbers without a derivable birth date are accepted.
+    pub fn reject_born_after(mut self, today: Date) -> Self {
+        self.reject_born_after = Some(today);
+        self
+    }
+
+    /// Rejects valid numbers of kinds outside `accept` with `NotAccepted`.
+    pub fn accept(mut self, accept: Accept) -> Self {
+        self.accept = accept;
+        self
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[non_exhaustive]
 pub enum NorwegianTinError {
+    /// The input has `found` characters, which is not a length of the number.
+    InvalidLength {
+        found: usize,
+    },
+    /// The character at byte `position` is not a digit.
+    NonNumericValue {
+        position: usize,
+    },
+    /// The check digits at `position` read `found`. `expected` is the value
+    /// that would have been valid, `None` when no value can be.
+    InvalidChecksum {
+        position: usize,
+        expected: Option<u8>,
+        found: u8,
+    },
+    /// The encoded date does not exist. The year has four digits when the
+    /// century is known, otherwise it is the two digits of the number.
+    InvalidDate {
+        year: u16,
+        month: u8,
+        day: u8,
+    },
+    WrongIdentifierType,
+    InvalidPrefix,
+    FutureBirthDate {
+        birth_date: Date,
+    },
+    /// A valid number of a kind the caller does not accept here.
+    NotAccepted {
+        found: TinKind,
+    },
+}
+
+/// The variant of a `NorwegianTinError` without its details, for matching
+/// and counting errors.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum ErrorKind {
     InvalidLength,
     NonNumericValue,
     InvalidChecksum,
     InvalidDate,
+    WrongIdentifierType,
+    InvalidPrefix,
+    FutureBirthDate,
+    NotAccepted,
 }
 
-impl std::fmt::Display for NorwegianTinError {
+impl NorwegianTinError {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            NorwegianTinError::InvalidLength { .. } => ErrorKind::InvalidLength,
+            NorwegianTinError::NonNumericValue { .. } => ErrorKind::NonNumericValue,
+            NorwegianTinError::InvalidChecksum { .. } => ErrorKind::InvalidChecksum,
+            NorwegianTinError::InvalidDate { .. } => ErrorKind::InvalidDate,
+            NorwegianTinError::WrongIdentifierType => ErrorKind::WrongIdentifierType,
+            NorwegianTinError::InvalidPrefix => ErrorKind::InvalidPrefix,
+            NorwegianTinError::FutureBirthDate { .. } => ErrorKind::FutureBirthDate,
+            NorwegianTinError::NotAccepted { .. } => ErrorKind::NotAccepted,
+        }
+    }
+}
+
+impl NorwegianTinError {
+    /// Moves the positions of the error by `offset`, for errors found in a
+    /// part of a larger input.
+    pub(crate) fn shifted(self, offset: usize) -> Self {
+        match self {
+            NorwegianTinError::NonNumericValue { position } => NorwegianTinError::NonNumericValue {
+                position: position + offset,
+            },
+            NorwegianTinError::InvalidChecksum {
+                position,
+                expected,
+                found,
+            } => NorwegianTinError::InvalidChecksum {
+                position: position + offset,
+                expected,
+                found,
+            },
+            err => err,
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            NorwegianTinError::InvalidLength => write!(f, "InvalidLength"),
-            NorwegianTinError::NonNumericValue => write!(f, "NonNumericValue"),
-            NorwegianTinError::InvalidChecksum => write!(f, "InvalidChecksum"),
-            NorwegianTinError::InvalidDate => write!(f, "InvalidDate"),
+            ErrorKind::InvalidLength => write!(f, "InvalidLength"),
+            ErrorKind::NonNumericValue => write!(f, "NonNumericValue"),
+            ErrorKind::InvalidChecksum => write!(f, "InvalidChecksum"),
+            ErrorKind::InvalidDate => write!(f, "InvalidDate"),
+            ErrorKind::WrongIdentifierType => write!(f, "WrongIdentifierType"),
+            ErrorKind::InvalidPrefix => write!(f, "InvalidPrefix"),
+            ErrorKind::FutureBirthDate => write!(f, "FutureBirthDate"),
+            ErrorKind::NotAccepted => write!(f, "NotAccepted"),
         }
     }
 }
 
+// Only the variant name is shown, as the details may include parts of the number
+impl std::fmt::Display for NorwegianTinError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.kind().fmt(f)
+    }
+}
+
+impl std::error::Error for NorwegianTinError {}
+
+/// Drops whitespace and the `.` and `-` separators of formatted input.
+pub(crate) fn strip_separators(s: &str) -> String {
+    s.chars()
+        .filter(|c| !c.is_whitespace() && *c != '.' && *c != '-')
+        .collect()
+}
+
+/// Returns whether `s` is a valid person or organisation number.
+pub fn is_valid(s: &str) -> bool {
+    match s.len() {
+        TIN_LENGTH => is_valid_person(s),
+        ORG_LENGTH => is_valid_org(s),
+        DUF_LENGTH => is_valid_duf(s),
+        _ => false,
+    }
+}
+
+/// Returns whether `s` is a valid F- or D-number.
+pub fn is_valid_person(s: &str) -> bool {
+    match <&[u8; TIN_LENGTH]>::try_from(s.as_bytes()) {
+        Ok(bytes) => NorwegianTin::check_person(bytes).is_ok(),
+        Err(_) => false,
+    }
+}
+
+/// Returns whether `s` is a valid organisation number.
+pub fn is_valid_org(s: &str) -> bool {
+    match <&[u8; ORG_LENGTH]>::try_from(s.as_bytes()) {
+        Ok(bytes) => NorwegianTin::check_org(bytes).is_ok(),
+        Err(_) => false,
+    }
+}
+
+/// Returns whether `s` is a valid DUF number.
+pub fn is_valid_duf(s: &str) -> bool {
+    match <&[u8; DUF_LENGTH]>::try_from(s.as_bytes()) {
+        Ok(bytes) => NorwegianTin::check_duf(bytes).is_ok(),
+        Err(_) => false,
+    }
+}
+
+/// Returns which kind of number `s` is shaped like, from its length, its
+/// digits and, for person numbers, the range of the day and month fields.
+/// Check digits are not verified, so a `Some` only means `s` is worth
+/// parsing.
+pub fn classify(s: &str) -> Option<TinShape> {
+    let bytes = s.as_bytes();
+    if !bytes.iter().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    match bytes.len() {
+        TIN_LENGTH => {
+            let day = (bytes[0] - b'0') * 10 + (bytes[1] - b'0');
+            let month = (bytes[2] - b'0') * 10 + (bytes[3] - b'0');
+            let day_ok = matches!(day, 1..=31 | 41..=71);
+            let month_ok = NorwegianTin::check_kind(bytes[2] - b'0')
+                .map(|kind| kind.get_base_month(month))
+                .map_or(false, |month| (1..=12).contains(&month));
+            (day_ok && month_ok).then(|| TinShape::Person)
+        }
+        ORG_LENGTH => Some(TinShape::Org),
+        DUF_LENGTH => Some(TinShape::Duf),
+        _ => None,
+    }
+}
+
 impl FromStr for NorwegianTin {
     type Err = NorwegianTinError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -61,9 +616,46 @@ impl FromStr for NorwegianTin {
     }
 }
 
-impl Into<String> for NorwegianTin {
-    fn into(self) -> String {
-        let bytes = self.get_value();
+impl TryFrom<&str> for NorwegianTin {
+    type Error = NorwegianTinError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<String> for NorwegianTin {
+    type Error = NorwegianTinError;
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(&s)
+    }
+}
+
+/// From the digits of a person number, each `0..=9` as returned by `value`.
+impl TryFrom<[u8; TIN_LENGTH]> for NorwegianTin {
+    type Error = NorwegianTinError;
+    fn try_from(digits: [u8; TIN_LENGTH]) -> Result<Self, Self::Error> {
+        if let Some(position) = digits.iter().position(|&d| d > 9) {
+            return Err(NorwegianTinError::NonNumericValue { position });
+        }
+        let mut text = digits;
+        for d in text.iter_mut() {
+            *d += b'0';
+        }
+        Self::parse_person(std::str::from_utf8(&text).expect("ASCII digits"))
+    }
+}
+
+/// From the digits of an organisation number, each `0..=9`.
+impl TryFrom<[u8; ORG_LENGTH]> for NorwegianTin {
+    type Error = NorwegianTinError;
+    fn try_from(digits: [u8; ORG_LENGTH]) -> Result<Self, Self::Error> {
+        OrgNumber::from_digits(digits).map(NorwegianTin::OrgNumber)
+    }
+}
+
+impl From<NorwegianTin> for String {
+    fn from(tin: NorwegianTin) -> String {
+        let bytes = tin.value();
         let s: String = bytes.iter().map(|&d| (d + b'0') as char).collect();
         s
     }
@@ -71,157 +663,1060 @@ impl Into<String> for NorwegianTin {
 
 impl AsRef<[u8]> for NorwegianTin {
     fn as_ref(&self) -> &[u8] {
-        self.get_value()
+        self.value()
     }
 }
 
 impl std::fmt::Display for NorwegianTin {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s: String = self.clone().into();
-        let kind = match self.get_kind() {
-            PersonKind::Anonymous => " (Anonymous) ",
-            PersonKind::HNumber => " (H-Number) ",
-            PersonKind::Synthetic => " (Synthetic) ",
-            PersonKind::Normal => "",
-        };
-        // Masking the last 5 digits for privacy
-        write!(f, "{}{}*****", kind, &s[0..6])
+        self.write_masked(f)
+    }
+}
+
+fn fmt_masked(out: &mut impl std::fmt::Write, kind: PersonKind, digits: &[u8]) -> std::fmt::Result {
+    out.write_str(mask_label(kind))?;
+    fmt_digits(out, &digits[0..6])?;
+    // Masking the last 5 digits for privacy
+    out.write_str("*****")
+}
+
+fn fmt_digits(out: &mut impl std::fmt::Write, digits: &[u8]) -> std::fmt::Result {
+    digits
+        .iter()
+        .try_for_each(|&d| out.write_char((d + b'0') as char))
+}
+
+/// The test ID category shown in front of masked numbers.
+pub(crate) fn mask_label(kind: PersonKind) -> &'static str {
+    match kind {
+        PersonKind::Anonymous => " (Anonymous) ",
+        PersonKind::HNumber => " (H-Number) ",
+        PersonKind::Synthetic => " (Synthetic) ",
+        PersonKind::Normal => "",
+    }
+}
+
+impl From<PersonNumber> for String {
+    fn from(person: PersonNumber) -> String {
+        person.value.iter().map(|&d| (d + b'0') as char).collect()
+    }
+}
+
+impl AsRef<[u8]> for PersonNumber {
+    fn as_ref(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+/// Masked like `NorwegianTin`, showing only the birth date digits.
+impl std::fmt::Display for PersonNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_masked(f, self.kind, &self.value)
+    }
+}
+
+impl From<OrgNumber> for String {
+    fn from(org: OrgNumber) -> String {
+        org.to_digit_string()
+    }
+}
+
+impl AsRef<[u8]> for OrgNumber {
+    fn as_ref(&self) -> &[u8] {
+        &self.value
     }
 }
+
+/// Organisation numbers are public, so they are shown in full.
+impl std::fmt::Display for OrgNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_digits(f, &self.value)
+    }
+}
+impl Tin for NorwegianTin {
+    type Kind = PersonKind;
+
+    fn kind(&self) -> PersonKind {
+        NorwegianTin::kind(self)
+    }
+
+    fn canonical_string(&self) -> String {
+        NorwegianTin::canonical_string(self)
+    }
+
+    fn is_test_id(&self) -> bool {
+        self.kind().is_test_id()
+    }
+
+    fn birth_date(&self) -> Option<Date> {
+        NorwegianTin::birth_date(self)
+    }
+}
+
 impl NorwegianTin {
-    pub fn get_value(&self) -> &[u8] {
+    /// The digits of the number, one per byte.
+    pub fn value(&self) -> &[u8] {
         match self {
             NorwegianTin::FNumber(fnr) => &fnr.value,
             NorwegianTin::DNumber(dnr) => &dnr.value,
             NorwegianTin::OrgNumber(org) => &org.value,
+            NorwegianTin::DufNumber(duf) => &duf.value,
         }
     }
-    pub fn get_kind(&self) -> PersonKind {
+    /// The test ID category. Organisation numbers are `Normal` or `Synthetic`
+    /// and DUF numbers always `Normal`; use `tin_kind` for the kind of number
+    /// and `person_kind` for the category of person numbers only.
+    pub fn kind(&self) -> PersonKind {
         match self {
             NorwegianTin::FNumber(fnr) => fnr.kind,
             NorwegianTin::DNumber(dnr) => dnr.kind,
-            NorwegianTin::OrgNumber(_) => PersonKind::Normal, // Org numbers are not categorized by kind
+            NorwegianTin::OrgNumber(org) => org.kind(),
+            NorwegianTin::DufNumber(_) => PersonKind::Normal,
+        }
+    }
+
+    pub fn tin_kind(&self) -> TinKind {
+        match self {
+            NorwegianTin::FNumber(_) => TinKind::FNumber,
+            NorwegianTin::DNumber(_) => TinKind::DNumber,
+            NorwegianTin::OrgNumber(_) => TinKind::OrgNumber,
+            NorwegianTin::DufNumber(_) => TinKind::DufNumber,
+        }
+    }
+
+    /// The category of F- and D-numbers, `None` for organisation and DUF numbers.
+    pub fn person_kind(&self) -> Option<PersonKind> {
+        self.as_person().map(|person| person.kind)
+    }
+
+    #[deprecated(since = "0.2.0", note = "use `value` instead")]
+    pub fn get_value(&self) -> &[u8] {
+        self.value()
+    }
+    #[deprecated(since = "0.2.0", note = "use `kind` instead")]
+    pub fn get_kind(&self) -> PersonKind {
+        self.kind()
+    }
+
+    /// The number of digits: 11 for person numbers, 9 for organisation
+    /// numbers and 12 for DUF numbers. A number is never empty.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.value().len()
+    }
+
+    /// Writes the number masked like `Display`, without allocating.
+    pub fn write_masked(&self, out: &mut impl std::fmt::Write) -> std::fmt::Result {
+        fmt_masked(out, self.kind(), self.value())
+    }
+
+    /// Writes the number as plain digits like `canonical_string`, without
+    /// allocating.
+    pub fn write_full(&self, out: &mut impl std::fmt::Write) -> std::fmt::Result {
+        fmt_digits(out, self.value())
+    }
+
+    /// The number as plain digits, without separators or masking.
+    pub fn canonical_string(&self) -> String {
+        (*self).into()
+    }
+
+    /// Whether a person number is an F-number or a D-number, `None` for
+    /// organisation and DUF numbers.
+    pub fn variant(&self) -> Option<PersonVariant> {
+        match self {
+            NorwegianTin::FNumber(_) => Some(PersonVariant::FNumber),
+            NorwegianTin::DNumber(_) => Some(PersonVariant::DNumber),
+            NorwegianTin::OrgNumber(_) | NorwegianTin::DufNumber(_) => None,
         }
     }
 
+    /// The series and category of person numbers together, `None` for
+    /// organisation and DUF numbers.
+    pub fn person_flags(&self) -> Option<PersonFlags> {
+        self.as_person().map(PersonNumber::flags)
+    }
+
+    /// Formats the full, unmasked number in its conventional grouping:
+    /// "ddmmyy nnnnn" for person numbers, "123 456 789" for organisation
+    /// numbers and "yyyy ssssss cc" for DUF numbers.
+    pub fn formatted(&self) -> String {
+        let s: String = (*self).into();
+        match self {
+            NorwegianTin::FNumber(_) | NorwegianTin::DNumber(_) => {
+                format!("{} {}", &s[0..6], &s[6..11])
+            }
+            NorwegianTin::OrgNumber(_) => format!("{} {} {}", &s[0..3], &s[3..6], &s[6..9]),
+            NorwegianTin::DufNumber(_) => format!("{} {} {}", &s[0..4], &s[4..10], &s[10..12]),
+        }
+    }
+
+    /// Whether `raw` is this number, ignoring whitespace, `.` and `-`
+    /// separators and the `NO` prefix and `MVA` suffix of VAT numbers.
+    pub fn matches_input(&self, raw: &str) -> bool {
+        let normalized = strip_separators(raw).to_ascii_uppercase();
+        let normalized = normalized.strip_prefix("NO").unwrap_or(&normalized);
+        let normalized = normalized.strip_suffix("MVA").unwrap_or(normalized);
+        normalized.len() == self.value().len()
+            && normalized
+                .bytes()
+                .zip(self.value())
+                .all(|(b, &d)| b == d + b'0')
+    }
+
+    pub fn is_f_number(&self) -> bool {
+        matches!(self, NorwegianTin::FNumber(_))
+    }
+
+    pub fn is_d_number(&self) -> bool {
+        matches!(self, NorwegianTin::DNumber(_))
+    }
+
+    pub fn is_org_number(&self) -> bool {
+        matches!(self, NorwegianTin::OrgNumber(_))
+    }
+
+    pub fn is_duf_number(&self) -> bool {
+        matches!(self, NorwegianTin::DufNumber(_))
+    }
+
+    /// The person number of F- and D-numbers.
+    pub fn as_person(&self) -> Option<&PersonNumber> {
+        match self {
+            NorwegianTin::FNumber(person) | NorwegianTin::DNumber(person) => Some(person),
+            _ => None,
+        }
+    }
+
+    pub fn as_org(&self) -> Option<&OrgNumber> {
+        match self {
+            NorwegianTin::OrgNumber(org) => Some(org),
+            _ => None,
+        }
+    }
+
+    pub fn as_duf(&self) -> Option<&DufNumber> {
+        match self {
+            NorwegianTin::DufNumber(duf) => Some(duf),
+            _ => None,
+        }
+    }
+
+    /// The birth date of person numbers. `None` for organisation and DUF
+    /// numbers, for new scheme numbers, and when the individual number does
+    /// not determine a century.
+    pub fn birth_date(&self) -> Option<Date> {
+        self.as_person()?.birth_date()
+    }
+
+    /// Whether the birth date lies from `from` to `to`, both included. `false`
+    /// when no birth date can be derived, see `birth_date`.
+    pub fn born_between(&self, from: Date, to: Date) -> bool {
+        self.birth_date()
+            .map_or(false, |date| from <= date && date <= to)
+    }
+
+    /// The scheme of person numbers, `None` for organisation and DUF numbers.
+    pub fn scheme(&self) -> Option<Scheme> {
+        self.as_person().map(PersonNumber::scheme)
+    }
+
+    /// The gender of person numbers. `None` for organisation and DUF numbers,
+    /// and for new scheme numbers.
+    pub fn gender(&self) -> Option<Gender> {
+        self.as_person()?.gender()
+    }
+
+    pub fn is_new_scheme(&self) -> bool {
+        self.scheme() == Some(Scheme::New)
+    }
+
     pub fn parse(s: &str) -> Result<NorwegianTin, NorwegianTinError> {
-        let bytes = s.as_bytes();
-        if bytes.len() != TIN_LENGTH && bytes.len() != ORG_LENGTH {
-            return Err(NorwegianTinError::InvalidLength);
+        match s.len() {
+            TIN_LENGTH => Self::parse_person(s),
+            ORG_LENGTH => Self::parse_org(s),
+            DUF_LENGTH => Self::parse_duf(s),
+            found => Err(NorwegianTinError::InvalidLength { found }),
+        }
+    }
+
+    pub fn parse_with_options(
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<NorwegianTin, NorwegianTinError> {
+        let tin = Self::parse(s)?;
+        if !options.accept.contains(tin.tin_kind()) {
+            return Err(NorwegianTinError::NotAccepted {
+                found: tin.tin_kind(),
+            });
+        }
+        if let Some(org) = tin.as_org() {
+            if options.strict_org_prefix && !org.is_enterprise_series() {
+                return Err(NorwegianTinError::InvalidPrefix);
+            }
+        }
+        if let (Some(today), Some(birth_date)) = (options.reject_born_after, tin.birth_date()) {
+            if birth_date > today {
+                return Err(NorwegianTinError::FutureBirthDate { birth_date });
+            }
+        }
+        Ok(tin)
+    }
+
+    /// Parses any number, then rejects kinds outside `accept` with
+    /// `NotAccepted`, e.g. organisation numbers on an endpoint for people.
+    pub fn parse_with(s: &str, accept: Accept) -> Result<NorwegianTin, NorwegianTinError> {
+        Self::parse_with_options(s, &ParseOptions::new().accept(accept))
+    }
+
+    /// Parses a person number, ignoring surrounding whitespace, and returns all
+    /// of its fields at once.
+    pub fn analyze(s: &str) -> Result<ParsedPerson, NorwegianTinError> {
+        let tin = Self::parse_person(s.trim())?;
+        let (variant, person) = match tin {
+            NorwegianTin::FNumber(person) => (PersonVariant::FNumber, person),
+            NorwegianTin::DNumber(person) => (PersonVariant::DNumber, person),
+            _ => return Err(NorwegianTinError::WrongIdentifierType),
+        };
+        let scheme = person.scheme();
+        Ok(ParsedPerson {
+            variant,
+            kind: person.kind,
+            scheme,
+            day: person.day(),
+            month: person.month(),
+            year: person.year(),
+            century: person.century(),
+            individual_number: person.individual_number(),
+            gender: person.gender(),
+            first_check_digit: person.value[9],
+            second_check_digit: person.value[10],
+            canonical: tin.into(),
+        })
+    }
+
+    /// Parses a number stored as an integer, e.g. in a BIGINT column, which
+    /// has lost the leading zeros of person numbers born on the 1st to the 9th.
+    /// `n` is padded with zeros to `width` and parsed as that kind only;
+    /// integers with more digits fail with `InvalidLength`.
+    pub fn from_number(n: u64, width: NumberWidth) -> Result<NorwegianTin, NorwegianTinError> {
+        let s = match width {
+            NumberWidth::Person => format!("{:011}", n),
+            NumberWidth::Org => format!("{:09}", n),
+            NumberWidth::Duf => format!("{:012}", n),
+        };
+        match width {
+            NumberWidth::Person if s.len() == TIN_LENGTH => Self::parse_person(&s),
+            NumberWidth::Org if s.len() == ORG_LENGTH => Self::parse_org(&s),
+            NumberWidth::Duf if s.len() == DUF_LENGTH => Self::parse_duf(&s),
+            _ => Err(NorwegianTinError::InvalidLength { found: s.len() }),
         }
+    }
+
+    /// Parses a person number (F- or D-number), rejecting organisation numbers
+    /// with `WrongIdentifierType` instead of accepting them based on length.
+    pub fn parse_person(s: &str) -> Result<NorwegianTin, NorwegianTinError> {
+        match s.len() {
+            TIN_LENGTH => {}
+            ORG_LENGTH | DUF_LENGTH => return Err(NorwegianTinError::WrongIdentifierType),
+            found => return Err(NorwegianTinError::InvalidLength { found }),
+        }
+        let person = match <&[u8; TIN_LENGTH]>::try_from(s.as_bytes()) {
+            Ok(bytes) => Self::check_person(bytes)?,
+            Err(_) => return Err(NorwegianTinError::InvalidLength { found: s.len() }),
+        };
+        // Determine if it's a D-number or F-number
+        match person.value[0] {
+            0..=3 => Ok(NorwegianTin::FNumber(person)),
+            _ => Ok(NorwegianTin::DNumber(person)),
+        }
+    }
 
+    /// Parses an organisation number, rejecting person numbers with
+    /// `WrongIdentifierType` instead of accepting them based on length.
+    pub fn parse_org(s: &str) -> Result<NorwegianTin, NorwegianTinError> {
+        match s.len() {
+            ORG_LENGTH => {}
+            TIN_LENGTH | DUF_LENGTH => return Err(NorwegianTinError::WrongIdentifierType),
+            found => return Err(NorwegianTinError::InvalidLength { found }),
+        }
+        let digits: [u8; ORG_LENGTH] = Self::to_digits(s)?;
+        Self::check_org(s.as_bytes().try_into().expect("length checked"))?;
+        Ok(NorwegianTin::OrgNumber(OrgNumber::new_unchecked(digits)))
+    }
+
+    /// Parses a DUF number, rejecting person and organisation numbers with
+    /// `WrongIdentifierType`.
+    pub fn parse_duf(s: &str) -> Result<NorwegianTin, NorwegianTinError> {
+        match s.len() {
+            DUF_LENGTH => {}
+            TIN_LENGTH | ORG_LENGTH => return Err(NorwegianTinError::WrongIdentifierType),
+            found => return Err(NorwegianTinError::InvalidLength { found }),
+        }
+        let digits: [u8; DUF_LENGTH] = Self::to_digits(s)?;
+        Self::check_duf(s.as_bytes().try_into().expect("length checked"))?;
+        Ok(NorwegianTin::DufNumber(DufNumber { value: digits }))
+    }
+
+    /// Builds a person number from digits (each `0..=9`) without validating
+    /// checksums or the date. Only use this for values that have already been
+    /// validated, e.g. when loading rows previously produced by `parse`.
+    pub fn new_unchecked(digits: [u8; TIN_LENGTH]) -> NorwegianTin {
+        let person = PersonNumber {
+            kind: Self::check_kind(digits[2]).unwrap_or(PersonKind::Normal),
+            value: digits,
+        };
+        match digits[0] {
+            0..=3 => NorwegianTin::FNumber(person),
+            _ => NorwegianTin::DNumber(person),
+        }
+    }
+
+    /// Validates the ASCII bytes of a person number: the digits, both
+    /// checksums and the encoded date.
+    pub(crate) fn check_person(
+        bytes: &[u8; TIN_LENGTH],
+    ) -> Result<PersonNumber, NorwegianTinError> {
+        let (digits, scheme) = Self::scan_person(bytes)?;
+        let kind = Self::check_person_fields(&digits, scheme)?;
+        Ok(PersonNumber {
+            kind,
+            value: digits,
+        })
+    }
+
+    /// Converts the ASCII bytes to digits and verifies both checksums in a
+    /// single branchless pass, accumulating the weighted sums alongside the
+    /// digit check. Returns the digits and the scheme the first check digit
+    /// belongs to.
+    fn scan_person(
+        bytes: &[u8; TIN_LENGTH],
+    ) -> Result<([u8; TIN_LENGTH], Scheme), NorwegianTinError> {
         let mut digits = [0u8; TIN_LENGTH];
-        for (i, &b) in bytes.iter().enumerate() {
-            if b < b'0' || b > b'9' {
-                return Err(NorwegianTinError::NonNumericValue);
+        let mut non_numeric = false;
+        let mut first = 0;
+        let mut second = 0;
+        for i in 0..TIN_LENGTH {
+            // Bytes below '0' wrap around, so a single comparison catches both ends
+            let d = bytes[i].wrapping_sub(b'0');
+            non_numeric |= d > 9;
+            digits[i] = d;
+            first += SEQUENCE_FIRST_CHECKSUM_DIGITS[i] * d as u32;
+            second += SEQUENCE_SECOND_CHECKSUM_DIGITS[i] * d as u32;
+        }
+        if non_numeric {
+            let position = bytes.iter().position(|b| !b.is_ascii_digit()).unwrap_or(0);
+            return Err(NorwegianTinError::NonNumericValue { position });
+        }
+        Self::check_weighted_sums(&digits, first, second)?;
+        let scheme = match first % 11 {
+            0 => Scheme::Classic,
+            _ => Scheme::New,
+        };
+        Ok((digits, scheme))
+    }
+
+    /// Checks the date fields of the digits of a person number whose
+    /// checksums are valid under `scheme`.
+    fn check_person_fields(
+        digits: &[u8; TIN_LENGTH],
+        scheme: Scheme,
+    ) -> Result<PersonKind, NorwegianTinError> {
+        let digit = |i: usize| digits[i];
+        let day = digit(0) * 10 + digit(1);
+        let month = digit(2) * 10 + digit(3);
+        let year = digit(4) * 10 + digit(5);
+        let invalid_date = NorwegianTinError::InvalidDate {
+            year: year as u16,
+            month,
+            day,
+        };
+        let kind = Self::check_kind(digit(2)).ok_or_else(|| invalid_date.clone())?;
+        let month = kind.get_base_month(month);
+        let actual_day = match digit(0) {
+            // F-number
+            0..=3 => day,
+            // D-number
+            4..=7 => day - 40,
+            _ => return Err(invalid_date),
+        };
+        let century = match scheme {
+            Scheme::Classic => {
+                let individual_number =
+                    digit(6) as u16 * 100 + digit(7) as u16 * 10 + digit(8) as u16;
+                classic_century(individual_number, year)
+            }
+            Scheme::New => None,
+        };
+        // Without a resolvable century the date is judged as 2000–2099, where every
+        // year divisible by four is a leap year, so no possible date is rejected
+        Date::new(century.unwrap_or(2000) + year as u16, month, actual_day).map_err(
+            |err| match (century, err) {
+                (None, NorwegianTinError::InvalidDate { month, day, .. }) => {
+                    NorwegianTinError::InvalidDate {
+                        year: year as u16,
+                        month,
+                        day,
+                    }
+                }
+                (_, err) => err,
+            },
+        )?;
+        Ok(kind)
+    }
+
+    fn check_weighted_sums(
+        digits: &[u8; TIN_LENGTH],
+        first: u32,
+        second: u32,
+    ) -> Result<(), NorwegianTinError> {
+        // From 2032 the first check digit may be any of the four values following the
+        // classic one, while the second check digit keeps its strict definition
+        let position = match (first % 11, second % 11) {
+            (0..=3, 0) => return Ok(()),
+            (0..=3, _) => 10,
+            _ => 9,
+        };
+        let sum = if position == 9 { first } else { second };
+        // Both check digits have weight 1 in their own sum, so removing them
+        // leaves the sum the classic check digit must complement
+        let found = digits[position];
+        let expected = match (11 - (sum - found as u32) % 11) % 11 {
+            10 => None,
+            v => Some(v as u8),
+        };
+        Err(NorwegianTinError::InvalidChecksum {
+            position,
+            expected,
+            found,
+        })
+    }
+
+    /// Verifies the digits and the check digit of the ASCII bytes of an
+    /// organisation number.
+    pub(crate) fn check_org(bytes: &[u8; ORG_LENGTH]) -> Result<(), NorwegianTinError> {
+        Self::check_numeric(bytes)?;
+        let found = bytes[8] - b'0';
+        Self::ascii_checksum(&bytes[0..8], SEQUENCE_ORG_CHECKSUM_DIGITS, |r| {
+            let expected = match (11 - r) % 11 {
+                10 => None,
+                v if v == found => return Ok(()),
+                v => Some(v),
+            };
+            Err(NorwegianTinError::InvalidChecksum {
+                position: 8,
+                expected,
+                found,
+            })
+        })
+    }
+
+    fn to_digits<const N: usize>(s: &str) -> Result<[u8; N], NorwegianTinError> {
+        let mut digits = [0u8; N];
+        for (i, &b) in s.as_bytes().iter().enumerate() {
+            if !b.is_ascii_digit() {
+                return Err(NorwegianTinError::NonNumericValue { position: i });
             }
             digits[i] = b - b'0';
         }
-        if bytes.len() == ORG_LENGTH {
-            let _ =
-                Self::calculate_checksum(
-                    &digits[0..8],
-                    SEQUENCE_ORG_CHECKSUM_DIGITS,
-                    |r| match (11 - r) % 11 {
-                        10 => Err(NorwegianTinError::InvalidChecksum),
-                        v if v == digits[8] => Ok(()),
-                        _ => Err(NorwegianTinError::InvalidChecksum),
-                    },
-                )?;
-
-            return Ok(NorwegianTin::OrgNumber(OrgNumber {
-                value: digits[0..9].try_into().unwrap(),
-            }));
-        }
-
-        let _ =
-            Self::calculate_checksum(&digits[0..9], SEQUENCE_FIRST_CHECKSUM_DIGITS, |r| {
-                match (r + digits[9]) % 11 {
-                    0..=3 => Ok(()),
-                    _ => Err(NorwegianTinError::InvalidChecksum),
-                }
-            })?;
-        let _ =
-            Self::calculate_checksum(
-                &digits[0..10],
-                SEQUENCE_SECOND_CHECKSUM_DIGITS,
-                |r| match (r + digits[10]) % 11 {
-                    0 => Ok(()),
-                    _ => Err(NorwegianTinError::InvalidChecksum),
-                },
-            )?;
-        let kind = Self::check_kind(digits[2])?;
+        Ok(digits)
+    }
+
+    fn check_kind(month: u8) -> Option<PersonKind> {
+        match month {
+            0..=1 => Some(PersonKind::Normal),
+            4..=5 => Some(PersonKind::HNumber),
+            6..=7 => Some(PersonKind::Anonymous),
+            8..=9 => Some(PersonKind::Synthetic),
+            _ => None,
+        }
+    }
+
+    /// Verifies the digits and the check digits of the ASCII bytes of a DUF
+    /// number.
+    pub(crate) fn check_duf(bytes: &[u8; DUF_LENGTH]) -> Result<(), NorwegianTinError> {
+        Self::check_numeric(bytes)?;
+        let digit = |i: usize| bytes[i] - b'0';
+        Self::ascii_checksum(&bytes[0..10], SEQUENCE_DUF_CHECKSUM_DIGITS, |r| {
+            match digit(10) * 10 + digit(11) {
+                v if v == r => Ok(()),
+                found => Err(NorwegianTinError::InvalidChecksum {
+                    position: 10,
+                    expected: Some(r),
+                    found,
+                }),
+            }
+        })
+    }
+
+    fn check_numeric(bytes: &[u8]) -> Result<(), NorwegianTinError> {
+        match bytes.iter().position(|b| !b.is_ascii_digit()) {
+            Some(position) => Err(NorwegianTinError::NonNumericValue { position }),
+            None => Ok(()),
+        }
+    }
+
+    /// Like `calculate_checksum`, for ASCII digits.
+    fn ascii_checksum<T: FnOnce(u8) -> Result<(), NorwegianTinError>>(
+        bytes: &[u8],
+        weights: &[u8],
+        matcher: T,
+    ) -> Result<(), NorwegianTinError> {
+        let sum: u32 = weights
+            .iter()
+            .zip(bytes.iter())
+            .map(|(&w, &b)| w as u32 * (b - b'0') as u32)
+            .sum();
+        let remainder = (sum % 11) as u8;
+        matcher(remainder)
+    }
+
+    pub(crate) fn calculate_checksum<T: FnOnce(u8) -> Result<(), NorwegianTinError>>(
+        digits: &[u8],
+        weights: &[u8],
+        matcher: T,
+    ) -> Result<(), NorwegianTinError> {
+        let sum: u32 = weights
+            .iter()
+            .zip(digits.iter())
+            .map(|(&w, &d)| w as u32 * d as u32)
+            .sum();
+        let remainder = (sum % 11) as u8;
+        matcher(remainder)
+    }
+}
+
+impl PersonNumber {
+    pub fn birth_date(&self) -> Option<Date> {
+        Some(Date {
+            year: self.century()? + self.year() as u16,
+            month: self.month(),
+            day: self.day(),
+        })
+    }
+
+    /// `None` for new scheme numbers, which no longer encode the gender.
+    pub fn gender(&self) -> Option<Gender> {
+        match (self.scheme(), self.value[8] % 2) {
+            (Scheme::New, _) => None,
+            (Scheme::Classic, 0) => Some(Gender::Female),
+            (Scheme::Classic, _) => Some(Gender::Male),
+        }
+    }
+
+    /// Whether a claimed date of birth matches the number, e.g. during
+    /// onboarding. The D-number day offset and the month offsets of test
+    /// numbers are removed first. When the century is not known, as for new
+    /// scheme numbers, only the last two digits of `year` are compared.
+    pub fn matches_birth_date(&self, day: u8, month: u8, year: u16) -> bool {
+        match self.birth_date() {
+            Some(date) => (date.day, date.month, date.year) == (day, month, year),
+            None => (self.day(), self.month(), self.year() as u16) == (day, month, year % 100),
+        }
+    }
+
+    /// Whether this is an F-number or a D-number, from the day field.
+    pub fn variant(&self) -> PersonVariant {
+        match self.value[0] {
+            0..=3 => PersonVariant::FNumber,
+            _ => PersonVariant::DNumber,
+        }
+    }
+
+    /// The category, from the month field.
+    pub fn kind(&self) -> PersonKind {
+        self.kind
+    }
+
+    pub fn flags(&self) -> PersonFlags {
+        PersonFlags {
+            variant: self.variant(),
+            kind: self.kind,
+        }
+    }
+
+    /// Deduplication hint: whether `other` has the same birth date and gender,
+    /// as when a D-number is later replaced by an F-number. Many people share
+    /// both, so a match only means the records are worth comparing further.
+    /// Numbers without a known century compare by day, month and two digit
+    /// year, and the gender only counts when both numbers encode one.
+    pub fn same_person_candidate(&self, other: &PersonNumber) -> bool {
+        let same_date = match (self.birth_date(), other.birth_date()) {
+            (Some(a), Some(b)) => a == b,
+            _ => {
+                (self.day(), self.month(), self.year())
+                    == (other.day(), other.month(), other.year())
+            }
+        };
+        let same_gender = match (self.gender(), other.gender()) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+        };
+        same_date && same_gender
+    }
+
+    /// The day field as encoded, including the D-number offset of 40.
+    pub fn day_field(&self) -> u8 {
+        self.value[0] * 10 + self.value[1]
+    }
+
+    /// The month field as encoded, including the offset of H-numbers,
+    /// anonymous and synthetic numbers.
+    pub fn month_field(&self) -> u8 {
+        self.value[2] * 10 + self.value[3]
+    }
+
+    /// The two digit year field, which is never offset.
+    pub fn year_field(&self) -> u8 {
+        self.year()
+    }
+
+    /// The two check digits, digits 10 and 11.
+    pub fn check_digits(&self) -> (u8, u8) {
+        (self.value[9], self.value[10])
+    }
+
+    /// Test tooling: the normal number with the same birth date and individual
+    /// number, for systems that reject the month offsets of H-numbers,
+    /// anonymous and synthetic numbers. The check digits are recomputed in the
+    /// same scheme, and D-numbers keep their day offset. The result belongs to
+    /// a real person, so only use it against test environments.
+    ///
+    /// Returns `None` when no valid check digit exists for the new number.
+    pub fn to_normal_equivalent(&self) -> Option<PersonNumber> {
+        if self.kind == PersonKind::Normal {
+            return Some(*self);
+        }
+        let weighted_sum = |weights: &[u32; TIN_LENGTH], digits: &[u8; TIN_LENGTH]| -> u32 {
+            weights
+                .iter()
+                .zip(digits.iter())
+                .map(|(&w, &d)| w * d as u32)
+                .sum()
+        };
+        // The first sum leaves 0 in the classic scheme and 1–3 in the new one
+        let remainder = weighted_sum(SEQUENCE_FIRST_CHECKSUM_DIGITS, &self.value) % 11;
+
+        let mut value = self.value;
+        let month = self.month();
+        value[2] = month / 10;
+        value[3] = month % 10;
+        value[9] = 0;
+        value[10] = 0;
+        match (remainder + 11 - weighted_sum(SEQUENCE_FIRST_CHECKSUM_DIGITS, &value) % 11) % 11 {
+            10 => return None,
+            digit => value[9] = digit as u8,
+        }
+        match (11 - weighted_sum(SEQUENCE_SECOND_CHECKSUM_DIGITS, &value) % 11) % 11 {
+            10 => return None,
+            digit => value[10] = digit as u8,
+        }
+        Some(PersonNumber {
+            kind: PersonKind::Normal,
+            value,
+        })
+    }
+
+    /// Day of birth, without the D-number offset.
+    fn day(&self) -> u8 {
+        let day = self.day_field();
+        match self.value[0] {
+            0..=3 => day,
+            _ => day - 40,
+        }
+    }
+
+    /// Month of birth, without the offset of the person kind.
+    fn month(&self) -> u8 {
+        self.kind.get_base_month(self.month_field())
+    }
+
+    /// Two digit year of birth.
+    fn year(&self) -> u8 {
+        self.value[4] * 10 + self.value[5]
+    }
+
+    /// The individual number, digits 7–9.
+    fn individual_number(&self) -> u16 {
+        self.value[6] as u16 * 100 + self.value[7] as u16 * 10 + self.value[8] as u16
+    }
+
+    pub fn scheme(&self) -> Scheme {
+        let first: u32 = SEQUENCE_FIRST_CHECKSUM_DIGITS
+            .iter()
+            .zip(self.value.iter())
+            .map(|(&w, &d)| w * d as u32)
+            .sum();
+        match first % 11 {
+            0 => Scheme::Classic,
+            _ => Scheme::New,
+        }
+    }
+
+    /// Resolves the century from the individual number (digits 7–9) and the
+    /// two digit birth year. New scheme numbers do not encode a century.
+    fn century(&self) -> Option<u16> {
+        if self.scheme() == Scheme::New {
+            return None;
+        }
+        self.classic_century()
+    }
+
+    /// The century the individual number stands for under the classic rules,
+    /// whatever the check digits say.
+    fn classic_century(&self) -> Option<u16> {
+        classic_century(self.individual_number(), self.year())
+    }
+}
+
+/// The century of a classic person number from its individual number and two
+/// digit birth year.
+fn classic_century(individual_number: u16, year: u8) -> Option<u16> {
+    match (individual_number, year) {
+        (0..=499, _) => Some(1900),
+        (500..=749, 54..=99) => Some(1800),
+        (500..=999, 0..=39) => Some(2000),
+        (900..=999, 40..=99) => Some(1900),
+        _ => None,
+    }
+}
+
+macro_rules! person_newtype {
+    ($name:ident, $accept:expr) => {
+        impl $name {
+            /// Parses like `NorwegianTin::parse`, rejecting valid numbers of
+            /// other kinds with `NotAccepted`.
+            pub fn parse(s: &str) -> Result<$name, NorwegianTinError> {
+                $name::try_from(NorwegianTin::parse_with(s, $accept)?)
+            }
+
+            pub fn person(&self) -> &PersonNumber {
+                &self.0
+            }
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = PersonNumber;
+
+            fn deref(&self) -> &PersonNumber {
+                &self.0
+            }
+        }
+
+        impl TryFrom<NorwegianTin> for $name {
+            type Error = NorwegianTinError;
+
+            fn try_from(tin: NorwegianTin) -> Result<Self, Self::Error> {
+                match tin {
+                    NorwegianTin::$name(person) => Ok($name(person)),
+                    _ => Err(NorwegianTinError::NotAccepted {
+                        found: tin.tin_kind(),
+                    }),
+                }
+            }
+        }
+
+        impl From<$name> for NorwegianTin {
+            fn from(number: $name) -> NorwegianTin {
+                NorwegianTin::$name(number.0)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = NorwegianTinError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::parse(s)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(number: $name) -> String {
+                number.0.into()
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+person_newtype!(FNumber, Accept::F_NUMBER);
+person_newtype!(DNumber, Accept::D_NUMBER);
+
+/// A valid number borrowing the string it was parsed from, for parsers that
+/// only inspect the value and never keep it. Convert with `to_tin` when an
+/// owned `NorwegianTin` is needed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct NorwegianTinRef<'a> {
+    text: &'a str,
+    kind: TinKind,
+}
+
+impl<'a> NorwegianTinRef<'a> {
+    /// Validates `s` like `NorwegianTin::parse` and keeps a reference to it.
+    pub fn parse(s: &'a str) -> Result<NorwegianTinRef<'a>, NorwegianTinError> {
+        let bytes = s.as_bytes();
+        // The checks run on the input bytes, without converting them to digits
+        let kind = if let Ok(bytes) = <&[u8; TIN_LENGTH]>::try_from(bytes) {
+            NorwegianTin::check_person(bytes)?;
+            match bytes[0] {
+                b'0'..=b'3' => TinKind::FNumber,
+                _ => TinKind::DNumber,
+            }
+        } else if let Ok(bytes) = <&[u8; ORG_LENGTH]>::try_from(bytes) {
+            NorwegianTin::check_org(bytes)?;
+            TinKind::OrgNumber
+        } else if let Ok(bytes) = <&[u8; DUF_LENGTH]>::try_from(bytes) {
+            NorwegianTin::check_duf(bytes)?;
+            TinKind::DufNumber
+        } else {
+            return Err(NorwegianTinError::InvalidLength { found: s.len() });
+        };
+        Ok(NorwegianTinRef { text: s, kind })
+    }
+
+    /// The original input, which is always plain digits.
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn tin_kind(&self) -> TinKind {
+        self.kind
+    }
+
+    /// The owned number, built from the digits without validating them again.
+    pub fn to_tin(&self) -> NorwegianTin {
+        let bytes = self.text.as_bytes();
+        match self.kind {
+            TinKind::FNumber | TinKind::DNumber => {
+                let mut digits = [0u8; TIN_LENGTH];
+                for (d, b) in digits.iter_mut().zip(bytes) {
+                    *d = b - b'0';
+                }
+                NorwegianTin::new_unchecked(digits)
+            }
+            TinKind::OrgNumber => {
+                let mut digits = [0u8; ORG_LENGTH];
+                for (d, b) in digits.iter_mut().zip(bytes) {
+                    *d = b - b'0';
+                }
+                NorwegianTin::OrgNumber(OrgNumber::new_unchecked(digits))
+            }
+            TinKind::DufNumber => {
+                let mut value = [0u8; DUF_LENGTH];
+                for (d, b) in value.iter_mut().zip(bytes) {
+                    *d = b - b'0';
+                }
+                NorwegianTin::DufNumber(DufNumber { value })
+            }
+        }
+    }
+}
+
+impl<'a> From<NorwegianTinRef<'a>> for NorwegianTin {
+    fn from(tin: NorwegianTinRef<'a>) -> NorwegianTin {
+        tin.to_tin()
+    }
+}
+
+impl AsRef<str> for NorwegianTinRef<'_> {
+    fn as_ref(&self) -> &str {
+        self.text
+    }
+}
+
+/// Masked like `NorwegianTin`.
+impl std::fmt::Display for NorwegianTinRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bytes = self.text.as_bytes();
+        let kind = match self.kind {
+            TinKind::FNumber | TinKind::DNumber => {
+                NorwegianTin::check_kind(bytes[2] - b'0').unwrap_or(PersonKind::Normal)
+            }
+            TinKind::OrgNumber if matches!(bytes[0], b'2' | b'3') => PersonKind::Synthetic,
+            TinKind::OrgNumber | TinKind::DufNumber => PersonKind::Normal,
+        };
+        f.write_str(mask_label(kind))?;
+        f.write_str(&self.text[0..6])?;
+        f.write_str("*****")
+    }
+}
+
+impl OrgNumber {
+    pub fn from_digits(digits: [u8; ORG_LENGTH]) -> Result<OrgNumber, NorwegianTinError> {
+        if let Some(position) = digits.iter().position(|&d| d > 9) {
+            return Err(NorwegianTinError::NonNumericValue { position });
+        }
+        let mut text = digits;
+        for d in text.iter_mut() {
+            *d += b'0';
+        }
+        NorwegianTin::check_org(&text)?;
+        Ok(OrgNumber::new_unchecked(digits))
+    }
 
-        let day = digits[0] * 10 + digits[1];
-        let month = kind.get_base_month(digits[2] * 10 + digits[3]);
-        let year = digits[4] as u16 * 10 + digits[5] as u16;
-        // Determine if it's a D-number or F-number
-        match digits[0] {
-            0..=3 => {
-                // F-number
-                if !Self::is_valid_date(day, month, year) {
-                    return Err(NorwegianTinError::InvalidDate);
-                }
-                Ok(NorwegianTin::FNumber(PersonNumber {
-                    kind: kind,
-                    value: digits,
-                }))
+    /// Completes the eight digit `base` (each `0..=9`) with its mod 11 check
+    /// digit. Fails with `InvalidChecksum` with `expected` `None` when the
+    /// remainder is 10, as no organisation number has that base.
+    pub fn complete(base: [u8; 8]) -> Result<OrgNumber, NorwegianTinError> {
+        let mut digits = [0u8; ORG_LENGTH];
+        digits[0..8].copy_from_slice(&base);
+        match OrgNumber::from_digits(digits) {
+            Err(NorwegianTinError::InvalidChecksum {
+                expected: Some(check),
+                ..
+            }) => {
+                digits[8] = check;
+                Ok(OrgNumber::new_unchecked(digits))
             }
-            4..=7 => {
-                let actual_day = day - 40;
-                if !Self::is_valid_date(actual_day, month, year) {
-                    return Err(NorwegianTinError::InvalidDate);
-                }
-                Ok(NorwegianTin::DNumber(PersonNumber {
-                    kind: kind,
-                    value: digits,
-                }))
-            }
-            _ => Err(NorwegianTinError::InvalidDate),
+            result => result,
         }
     }
 
-    fn check_kind(month: u8) -> Result<PersonKind, NorwegianTinError> {
-        match month {
-            0..=1 => Ok(PersonKind::Normal),
-            4..=5 => Ok(PersonKind::HNumber),
-            6..=7 => Ok(PersonKind::Anonymous),
-            8..=9 => Ok(PersonKind::Synthetic),
-            _ => Err(NorwegianTinError::InvalidDate),
-        }
+    /// Builds an organisation number from digits (each `0..=9`) without
+    /// validating the checksum. Only use this for already validated values.
+    pub fn new_unchecked(digits: [u8; ORG_LENGTH]) -> OrgNumber {
+        OrgNumber { value: digits }
     }
 
-    fn calculate_checksum<T: FnOnce(u8) -> Result<(), NorwegianTinError>>(
-        digits: &[u8],
-        weights: &[u8],
-        matcher: T,
-    ) -> Result<(), NorwegianTinError> {
-        let sum: u32 = weights
-            .iter()
-            .zip(digits.iter())
-            .map(|(&w, &d)| w as u32 * d as u32)
-            .sum();
-        let remainder = (sum % 11) as u8;
-        matcher(remainder)
+    /// The eight digits before the check digit.
+    pub fn base_digits(&self) -> [u8; 8] {
+        let mut base = [0u8; 8];
+        base.copy_from_slice(&self.value[0..8]);
+        base
+    }
+
+    pub fn check_digit(&self) -> u8 {
+        self.value[8]
+    }
+
+    /// The nine digits as a string, e.g. "974760673".
+    pub fn to_digit_string(&self) -> String {
+        self.value.iter().map(|&d| (d + b'0') as char).collect()
+    }
+
+    /// Returns whether the number is in the 8xx xxx xxx or 9xx xxx xxx series
+    /// assigned to real entities by Enhetsregisteret.
+    pub fn is_enterprise_series(&self) -> bool {
+        matches!(self.value[0], 8 | 9)
+    }
+
+    /// Returns whether the number belongs to the series used for synthetic
+    /// organisations in Tenor test data (leading digit 2 or 3).
+    pub fn is_synthetic(&self) -> bool {
+        matches!(self.value[0], 2 | 3)
     }
 
-    fn is_valid_date(day: u8, month: u8, year: u16) -> bool {
-        if month == 0 || month > 12 || day == 0 || year >= 100 {
-            return false;
+    /// Org numbers are only categorized as `Normal` or `Synthetic`.
+    pub fn kind(&self) -> PersonKind {
+        if self.is_synthetic() {
+            PersonKind::Synthetic
+        } else {
+            PersonKind::Normal
         }
-        let days_in_month = match month {
-            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-            4 | 6 | 9 | 11 => 30,
-            2 => {
-                if year % 4 == 0 {
-                    29
-                } else {
-                    28
-                }
-            }
-            _ => return false,
-        };
-        day <= days_in_month
     }
 }
 
@@ -251,11 +1746,11 @@ mod test {
     pub use super::*;
     #[test]
     fn test_invalid_length() {
-        let tins = vec!["0123456789", "123456789012", "123", "12345678", ""];
+        let tins = vec!["0123456789", "1234567890123", "123", "12345678", ""];
         for tin in tins {
             assert_eq!(
-                NorwegianTin::parse(tin).unwrap_err(),
-                NorwegianTinError::InvalidLength
+                NorwegianTin::parse(tin).unwrap_err().kind(),
+                ErrorKind::InvalidLength
             );
         }
     }
@@ -264,8 +1759,8 @@ mod test {
         let tins = vec!["1234567890a", "abcdefghijk", "12345abc678"];
         for tin in tins {
             assert_eq!(
-                NorwegianTin::parse(tin).unwrap_err(),
-                NorwegianTinError::NonNumericValue
+                NorwegianTin::parse(tin).unwrap_err().kind(),
+                ErrorKind::NonNumericValue
             );
         }
     }
@@ -287,12 +1782,52 @@ mod test {
         ];
         for tin in tins {
             assert_eq!(
-                NorwegianTin::parse(tin).unwrap_err(),
-                NorwegianTinError::InvalidChecksum
+                NorwegianTin::parse(tin).unwrap_err().kind(),
+                ErrorKind::InvalidChecksum
             );
         }
     }
 
+    #[test]
+    fn test_error_details() {
+        let tins = vec![
+            ("123", NorwegianTinError::InvalidLength { found: 3 }),
+            (
+                "12345abc678",
+                NorwegianTinError::NonNumericValue { position: 5 },
+            ),
+            (
+                "12345678901",
+                NorwegianTinError::InvalidChecksum {
+                    position: 9,
+                    expected: Some(1),
+                    found: 0,
+                },
+            ),
+            (
+                "16057902285",
+                NorwegianTinError::InvalidChecksum {
+                    position: 10,
+                    expected: Some(4),
+                    found: 5,
+                },
+            ),
+            (
+                "974760674",
+                NorwegianTinError::InvalidChecksum {
+                    position: 8,
+                    expected: Some(3),
+                    found: 4,
+                },
+            ),
+        ];
+        for (tin, expected) in tins {
+            let err = NorwegianTin::parse(tin).unwrap_err();
+            assert_eq!(err, expected);
+            assert_eq!(err.to_string(), err.kind().to_string());
+        }
+    }
+
     #[test]
     fn test_2032_format() {
         let tins = vec!["11010000000", "11010000019", "11010000027", "11010000035"];
@@ -301,14 +1836,469 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_scheme() {
+        let tins = vec![
+            ("11010000000", Some(Scheme::Classic)),
+            ("16057902284", Some(Scheme::Classic)),
+            ("11010000019", Some(Scheme::New)),
+            ("11010000027", Some(Scheme::New)),
+            ("11010000035", Some(Scheme::New)),
+            ("974760673", None),
+        ];
+        for (tin, expected) in tins {
+            let tin = NorwegianTin::parse(tin).unwrap();
+            assert_eq!(tin.scheme(), expected);
+            assert_eq!(tin.is_new_scheme(), expected == Some(Scheme::New));
+        }
+    }
+
+    #[test]
+    fn test_new_scheme_birth_date() {
+        let tin = NorwegianTin::parse("11010000000").unwrap();
+        assert!(tin.birth_date().is_some());
+        let tin = NorwegianTin::parse("11010000019").unwrap();
+        assert_eq!(tin.birth_date(), None);
+    }
+
     #[test]
     fn test_invalid_date() {
         let tins = vec!["00000000000", "11001000073"];
         for tin in tins {
             assert_eq!(
-                NorwegianTin::parse(tin).unwrap_err(),
-                NorwegianTinError::InvalidDate
+                NorwegianTin::parse(tin).unwrap_err().kind(),
+                ErrorKind::InvalidDate
+            );
+        }
+        // The year has four digits only when the century is known
+        assert_eq!(
+            NorwegianTin::parse("29020012380"),
+            Err(NorwegianTinError::InvalidDate {
+                year: 1900,
+                month: 2,
+                day: 29
+            })
+        );
+        assert_eq!(
+            NorwegianTin::parse("30025060020"),
+            Err(NorwegianTinError::InvalidDate {
+                year: 50,
+                month: 2,
+                day: 30
+            })
+        );
+    }
+
+    #[test]
+    fn test_analyze() {
+        let parsed = NorwegianTin::analyze(" 16057902284\n").unwrap();
+        assert_eq!(
+            parsed,
+            ParsedPerson {
+                variant: PersonVariant::FNumber,
+                kind: PersonKind::Normal,
+                scheme: Scheme::Classic,
+                day: 16,
+                month: 5,
+                year: 79,
+                century: Some(1900),
+                individual_number: 22,
+                gender: Some(Gender::Female),
+                first_check_digit: 8,
+                second_check_digit: 4,
+                canonical: "16057902284".to_string(),
+            }
+        );
+
+        let parsed = NorwegianTin::analyze("70887100797").unwrap();
+        assert_eq!(parsed.variant, PersonVariant::DNumber);
+        assert_eq!(parsed.kind, PersonKind::Synthetic);
+        assert_eq!((parsed.day, parsed.month, parsed.year), (30, 8, 71));
+        assert_eq!(parsed.gender, Some(Gender::Male));
+
+        let parsed = NorwegianTin::analyze("11010000019").unwrap();
+        assert_eq!(parsed.scheme, Scheme::New);
+        assert_eq!(parsed.century, None);
+        assert_eq!(parsed.gender, None);
+
+        assert_eq!(
+            NorwegianTin::analyze("974760673").unwrap_err(),
+            NorwegianTinError::WrongIdentifierType
+        );
+        assert_eq!(
+            NorwegianTin::analyze("16057902285").unwrap_err().kind(),
+            ErrorKind::InvalidChecksum
+        );
+    }
+
+    #[test]
+    fn test_accessors() {
+        let tins = vec![
+            ("16057902284", 11, Some(PersonVariant::FNumber)),
+            ("56865400190", 11, Some(PersonVariant::DNumber)),
+            ("974760673", 9, None),
+            ("201234567800", 12, None),
+        ];
+        for (value, len, variant) in tins {
+            let tin = NorwegianTin::parse(value).unwrap();
+            assert_eq!(tin.len(), len);
+            assert_eq!(tin.value().len(), len);
+            assert_eq!(tin.canonical_string(), value);
+            assert_eq!(tin.variant(), variant);
+        }
+    }
+
+    #[test]
+    fn test_org_accessors() {
+        let tin = NorwegianTin::parse("974760673").unwrap();
+        let org = tin.as_org().unwrap();
+        assert_eq!(org.base_digits(), [9, 7, 4, 7, 6, 0, 6, 7]);
+        assert_eq!(org.check_digit(), 3);
+        assert_eq!(org.to_digit_string(), "974760673");
+        assert!(org.is_enterprise_series());
+
+        let org = OrgNumber::from_digits([3, 1, 5, 5, 4, 3, 6, 6, 5]).unwrap();
+        assert_eq!(org.to_digit_string(), "315543665");
+        assert!(!org.is_enterprise_series());
+        assert_eq!(std::mem::size_of::<OrgNumber>(), ORG_LENGTH);
+    }
+
+    #[test]
+    fn test_person_fields() {
+        let tins = vec![
+            ("16057902284", (16, 5, 79), (16, 5, 79), (8, 4)),
+            ("56865400190", (56, 86, 54), (16, 6, 54), (9, 0)),
+            ("70887100797", (70, 88, 71), (30, 8, 71), (9, 7)),
+        ];
+        for (value, fields, birth, check_digits) in tins {
+            let tin = NorwegianTin::parse(value).unwrap();
+            let person = tin.as_person().unwrap();
+            assert_eq!(
+                (
+                    person.day_field(),
+                    person.month_field(),
+                    person.year_field()
+                ),
+                fields
             );
+            assert_eq!((person.day(), person.month(), person.year()), birth);
+            assert_eq!(person.check_digits(), check_digits);
+        }
+    }
+
+    #[test]
+    fn test_leaf_display() {
+        let tin = NorwegianTin::parse("70887100797").unwrap();
+        let person = *tin.as_person().unwrap();
+        assert_eq!(person.to_string(), " (Synthetic) 708871*****");
+        assert_eq!(person.to_string(), tin.to_string());
+        assert_eq!(String::from(person), "70887100797");
+        assert_eq!(person.as_ref(), tin.value());
+
+        let tin = NorwegianTin::parse("974760673").unwrap();
+        let org = *tin.as_org().unwrap();
+        assert_eq!(org.to_string(), "974760673");
+        assert_eq!(String::from(org), "974760673");
+        assert_eq!(org.as_ref(), &[9, 7, 4, 7, 6, 0, 6, 7, 3]);
+    }
+
+    #[test]
+    fn test_to_normal_equivalent() {
+        let tins = vec![
+            ("16057902284", Some("16057902284")),
+            ("70887100797", Some("70087100721")),
+            ("56865400190", Some("56065400125")),
+            ("22517149261", Some("22117149289")),
+            ("01858500075", None),
+        ];
+        for (value, expected) in tins {
+            let tin = NorwegianTin::parse(value).unwrap();
+            let normal = tin.as_person().unwrap().to_normal_equivalent();
+            assert_eq!(normal.map(String::from).as_deref(), expected);
+            if let Some(normal) = normal {
+                let parsed = NorwegianTin::parse(&String::from(normal)).unwrap();
+                assert_eq!(parsed.kind(), PersonKind::Normal);
+                assert_eq!(parsed.tin_kind(), tin.tin_kind());
+                assert_eq!(parsed.birth_date(), tin.birth_date());
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let tins = vec![
+            ("16057902284", Accept::PERSON, Ok(())),
+            ("70887100797", Accept::F_NUMBER | Accept::D_NUMBER, Ok(())),
+            ("974760673", Accept::ORG_NUMBER, Ok(())),
+            ("201234567800", Accept::ALL, Ok(())),
+            (
+                "974760673",
+                Accept::PERSON,
+                Err(NorwegianTinError::NotAccepted {
+                    found: TinKind::OrgNumber,
+                }),
+            ),
+            (
+                "70887100797",
+                Accept::F_NUMBER | Accept::ORG_NUMBER,
+                Err(NorwegianTinError::NotAccepted {
+                    found: TinKind::DNumber,
+                }),
+            ),
+            ("201234567800", Accept::default() | Accept::PERSON, Ok(())),
+        ];
+        for (tin, accept, expected) in tins {
+            assert_eq!(NorwegianTin::parse_with(tin, accept).map(|_| ()), expected);
+        }
+        // Invalid numbers keep their own error
+        assert_eq!(
+            NorwegianTin::parse_with("16057902285", Accept::ORG_NUMBER)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidChecksum
+        );
+    }
+
+    #[test]
+    fn test_typed_person_numbers() {
+        fn birth_year(number: &FNumber) -> Option<u16> {
+            number.birth_date().map(|date| date.year)
+        }
+        let fnr = FNumber::parse("16057902284").unwrap();
+        assert_eq!(birth_year(&fnr), Some(1979));
+        assert_eq!(String::from(fnr), "16057902284");
+        assert_eq!(
+            NorwegianTin::from(fnr),
+            NorwegianTin::parse("16057902284").unwrap()
+        );
+        assert_eq!(
+            "70887100797".parse::<FNumber>(),
+            Err(NorwegianTinError::NotAccepted {
+                found: TinKind::DNumber
+            })
+        );
+
+        let dnr = DNumber::parse("70887100797").unwrap();
+        assert_eq!(dnr.kind, PersonKind::Synthetic);
+        assert_eq!(dnr.to_string(), " (Synthetic) 708871*****");
+        assert_eq!(
+            DNumber::try_from(NorwegianTin::parse("974760673").unwrap()),
+            Err(NorwegianTinError::NotAccepted {
+                found: TinKind::OrgNumber
+            })
+        );
+        assert_eq!(
+            DNumber::parse("16057902285").unwrap_err().kind(),
+            ErrorKind::InvalidChecksum
+        );
+    }
+
+    #[test]
+    fn test_tin_kind() {
+        let tins = vec![
+            ("16057902284", TinKind::FNumber, Some(PersonKind::Normal)),
+            ("70887100797", TinKind::DNumber, Some(PersonKind::Synthetic)),
+            ("974760673", TinKind::OrgNumber, None),
+            ("201234567800", TinKind::DufNumber, None),
+        ];
+        for (value, tin_kind, person_kind) in tins {
+            let tin = NorwegianTin::parse(value).unwrap();
+            assert_eq!(tin.tin_kind(), tin_kind);
+            assert_eq!(tin.person_kind(), person_kind);
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_accessors() {
+        let tin = NorwegianTin::parse("70887100797").unwrap();
+        assert_eq!(tin.get_value(), tin.value());
+        assert_eq!(tin.get_kind(), tin.kind());
+    }
+
+    #[test]
+    fn test_write_masked_and_full() {
+        struct Fixed {
+            buffer: [u8; 32],
+            len: usize,
+        }
+        impl std::fmt::Write for Fixed {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                let end = self.len + s.len();
+                self.buffer
+                    .get_mut(self.len..end)
+                    .ok_or(std::fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+        for input in ["16057902284", "08639815316", "974760673"] {
+            let tin = NorwegianTin::parse(input).unwrap();
+            let mut out = Fixed {
+                buffer: [0; 32],
+                len: 0,
+            };
+            tin.write_masked(&mut out).unwrap();
+            assert_eq!(&out.buffer[..out.len], tin.to_string().as_bytes());
+            out.len = 0;
+            tin.write_full(&mut out).unwrap();
+            assert_eq!(&out.buffer[..out.len], input.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_formatted() {
+        let tins = vec![
+            ("16057902284", "160579 02284"),
+            ("70887100797", "708871 00797"),
+            ("974760673", "974 760 673"),
+            ("201234567800", "2012 345678 00"),
+        ];
+        for (tin, expected) in tins {
+            let tin = NorwegianTin::parse(tin).unwrap();
+            assert_eq!(tin.formatted(), expected);
+            assert!(tin.matches_input(&tin.formatted()));
+        }
+    }
+
+    #[test]
+    fn test_matches_input() {
+        let tin = NorwegianTin::parse("974760673").unwrap();
+        let inputs = vec![
+            ("974760673", true),
+            ("974 760 673", true),
+            ("974.760.673", true),
+            ("NO 974 760 673 MVA", true),
+            ("no974760673mva", true),
+            ("974760673MVA", true),
+            ("974760674", false),
+            ("97476067", false),
+            ("NO NO 974760673", false),
+            ("", false),
+        ];
+        for (input, expected) in inputs {
+            assert_eq!(tin.matches_input(input), expected, "{}", input);
+        }
+        let tin = NorwegianTin::parse("16057902284").unwrap();
+        assert!(tin.matches_input("160579 02284"));
+        assert!(tin.matches_input("160579-02284"));
+        assert!(!tin.matches_input("16057902285"));
+    }
+
+    #[test]
+    fn test_variant_accessors() {
+        let tin = NorwegianTin::parse("16057902284").unwrap();
+        assert!(tin.is_f_number() && !tin.is_d_number() && !tin.is_org_number());
+        assert_eq!(tin.as_person().unwrap().birth_date(), tin.birth_date());
+        assert!(tin.as_org().is_none() && tin.as_duf().is_none());
+
+        let tin = NorwegianTin::parse("70887100797").unwrap();
+        assert!(tin.is_d_number() && !tin.is_f_number());
+        assert!(tin.as_person().is_some());
+
+        let tin = NorwegianTin::parse("974760673").unwrap();
+        assert!(tin.is_org_number() && !tin.is_duf_number());
+        assert!(tin.as_org().unwrap().is_enterprise_series());
+        assert!(tin.as_person().is_none());
+
+        let tin = NorwegianTin::parse("201234567800").unwrap();
+        assert!(tin.is_duf_number());
+        assert!(tin.as_duf().is_some() && tin.as_org().is_none());
+    }
+
+    #[test]
+    fn test_gender() {
+        let tins = vec![
+            ("16057902284", Some(Gender::Female)),
+            ("70887100797", Some(Gender::Male)),
+            ("01010150074", Some(Gender::Female)),
+            ("11010000000", Some(Gender::Female)),
+            ("11010000019", None),
+            ("974760673", None),
+        ];
+        for (tin, expected) in tins {
+            assert_eq!(NorwegianTin::parse(tin).unwrap().gender(), expected);
+        }
+    }
+
+    #[test]
+    fn test_person_flags() {
+        let tests = vec![
+            ("16057902284", PersonVariant::FNumber, PersonKind::Normal),
+            ("70887100797", PersonVariant::DNumber, PersonKind::Synthetic),
+            ("08639815316", PersonVariant::FNumber, PersonKind::Anonymous),
+            // H-series D-numbers, born 31 May 1926 and 14 August 1959
+            ("71452625568", PersonVariant::DNumber, PersonKind::HNumber),
+            ("54485908229", PersonVariant::DNumber, PersonKind::HNumber),
+            ("45861672755", PersonVariant::DNumber, PersonKind::Synthetic),
+        ];
+        for (input, variant, kind) in tests {
+            let tin = NorwegianTin::parse(input).unwrap();
+            let flags = tin.person_flags().unwrap();
+            assert_eq!(flags, PersonFlags { variant, kind }, "{}", input);
+            assert_eq!(tin.variant(), Some(variant));
+            assert_eq!(tin.person_kind(), Some(kind));
+            assert_eq!(tin.as_person().unwrap().variant(), variant);
+            assert_eq!(tin.as_person().unwrap().kind(), kind);
+        }
+        let h_number = NorwegianTin::parse("71452625568").unwrap();
+        assert!(h_number.is_d_number());
+        assert_eq!(h_number.birth_date(), Date::new(1926, 5, 31).ok());
+        let h_number = NorwegianTin::parse("54485908229").unwrap();
+        assert_eq!(h_number.birth_date(), Date::new(1959, 8, 14).ok());
+        assert_eq!(
+            NorwegianTin::parse("974760673").unwrap().person_flags(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_matches_birth_date() {
+        let person = |s: &str| *NorwegianTin::parse(s).unwrap().as_person().unwrap();
+        assert!(person("16057902284").matches_birth_date(16, 5, 1979));
+        assert!(!person("16057902284").matches_birth_date(16, 5, 2079));
+        assert!(!person("16057902284").matches_birth_date(15, 5, 1979));
+        // D-number born 30 August 1971
+        assert!(person("70887100797").matches_birth_date(30, 8, 1971));
+        // Anonymous, born 8 March 1998
+        assert!(person("08639815316").matches_birth_date(8, 3, 1998));
+        // New scheme numbers do not tell the century
+        assert!(person("11010000019").matches_birth_date(11, 1, 2000));
+        assert!(person("11010000019").matches_birth_date(11, 1, 1900));
+    }
+
+    #[test]
+    fn test_same_person_candidate() {
+        let day = Date::new(1985, 3, 14).unwrap();
+        let tins: Vec<NorwegianTin> = generate::iter_range(day, day, Accept::PERSON).collect();
+        let pick = |d_number: bool, gender: Gender| {
+            *tins
+                .iter()
+                .find(|tin| tin.is_d_number() == d_number && tin.gender() == Some(gender))
+                .unwrap()
+                .as_person()
+                .unwrap()
+        };
+        let f_number = pick(false, Gender::Female);
+        let d_number = pick(true, Gender::Female);
+        assert!(f_number.same_person_candidate(&d_number));
+        assert!(d_number.same_person_candidate(&f_number));
+        assert!(!f_number.same_person_candidate(&pick(true, Gender::Male)));
+        let other_day = NorwegianTin::parse("16057902284").unwrap();
+        assert!(!f_number.same_person_candidate(other_day.as_person().unwrap()));
+    }
+
+    #[test]
+    fn test_leap_day_century() {
+        // 1900 is not a leap year, 2000 and 1896 are
+        assert_eq!(
+            NorwegianTin::parse("29020012380").unwrap_err().kind(),
+            ErrorKind::InvalidDate
+        );
+        let tins = vec!["29020050088", "29029650037", "29020412354"];
+        for tin in tins {
+            assert!(NorwegianTin::parse(tin).is_ok());
         }
     }
 
@@ -386,15 +2376,12 @@ mod test {
             "02013299997",
         ];
         for tin in tins {
-            assert_eq!(NorwegianTin::parse(tin).is_ok(), true);
+            assert!(NorwegianTin::parse(tin).is_ok());
             assert!(matches!(
                 NorwegianTin::parse(tin).unwrap(),
                 NorwegianTin::FNumber(_)
             ));
-            assert_eq!(
-                NorwegianTin::parse(tin).unwrap().get_kind(),
-                PersonKind::Normal
-            );
+            assert_eq!(NorwegianTin::parse(tin).unwrap().kind(), PersonKind::Normal);
         }
     }
     #[test]
@@ -453,13 +2440,13 @@ mod test {
         ];
 
         for tin in tins {
-            assert_eq!(NorwegianTin::parse(tin).is_ok(), true);
+            assert!(NorwegianTin::parse(tin).is_ok());
             assert!(matches!(
                 NorwegianTin::parse(tin).unwrap(),
                 NorwegianTin::FNumber(_)
             ));
             assert_eq!(
-                NorwegianTin::parse(tin).unwrap().get_kind(),
+                NorwegianTin::parse(tin).unwrap().kind(),
                 PersonKind::HNumber
             );
         }
@@ -520,13 +2507,13 @@ mod test {
             "70924700201",
         ];
         for tin in dnr {
-            assert_eq!(NorwegianTin::parse(tin).is_ok(), true);
+            assert!(NorwegianTin::parse(tin).is_ok());
             assert!(matches!(
                 NorwegianTin::parse(tin).unwrap(),
                 NorwegianTin::DNumber(_)
             ));
             assert_eq!(
-                NorwegianTin::parse(tin).unwrap().get_kind(),
+                NorwegianTin::parse(tin).unwrap().kind(),
                 PersonKind::Synthetic
             );
         }
@@ -556,13 +2543,13 @@ mod test {
             "20640081725",
         ];
         for tin in tins {
-            assert_eq!(NorwegianTin::parse(tin).is_ok(), true);
+            assert!(NorwegianTin::parse(tin).is_ok());
             assert!(matches!(
                 NorwegianTin::parse(tin).unwrap(),
                 NorwegianTin::FNumber(_)
             ));
             assert_eq!(
-                NorwegianTin::parse(tin).unwrap().get_kind(),
+                NorwegianTin::parse(tin).unwrap().kind(),
                 PersonKind::Anonymous
             );
         }
@@ -634,9 +2621,373 @@ mod test {
         let orgs = vec!["905661834", "085649778", "255399984", "917766151"];
         for org in orgs {
             assert_eq!(
-                NorwegianTin::parse(org).unwrap_err(),
-                NorwegianTinError::InvalidChecksum
+                NorwegianTin::parse(org).unwrap_err().kind(),
+                ErrorKind::InvalidChecksum
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_person() {
+        assert!(matches!(
+            NorwegianTin::parse_person("16057902284").unwrap(),
+            NorwegianTin::FNumber(_)
+        ));
+        assert!(matches!(
+            NorwegianTin::parse_person("70887100797").unwrap(),
+            NorwegianTin::DNumber(_)
+        ));
+        let orgs = vec!["905661833", "905661834", "90566183a"];
+        for org in orgs {
+            assert_eq!(
+                NorwegianTin::parse_person(org).unwrap_err(),
+                NorwegianTinError::WrongIdentifierType
+            );
+        }
+        assert_eq!(
+            NorwegianTin::parse_person("123").unwrap_err().kind(),
+            ErrorKind::InvalidLength
+        );
+    }
+    #[test]
+    fn test_parse_org() {
+        assert!(matches!(
+            NorwegianTin::parse_org("905661833").unwrap(),
+            NorwegianTin::OrgNumber(_)
+        ));
+        let tins = vec!["16057902284", "12345678901", "1234567890a"];
+        for tin in tins {
+            assert_eq!(
+                NorwegianTin::parse_org(tin).unwrap_err(),
+                NorwegianTinError::WrongIdentifierType
+            );
+        }
+        assert_eq!(
+            NorwegianTin::parse_org("905661834").unwrap_err().kind(),
+            ErrorKind::InvalidChecksum
+        );
+    }
+    #[test]
+    fn test_is_valid() {
+        let valid = vec!["16057902284", "70887100797", "08639815316", "905661833"];
+        for tin in valid {
+            assert!(is_valid(tin));
+        }
+        let invalid = vec![
+            "",
+            "123",
+            "12345678901",
+            "00000000000",
+            "905661834",
+            "1234567890a",
+        ];
+        for tin in invalid {
+            assert!(!is_valid(tin));
+        }
+        assert!(is_valid_person("16057902284"));
+        assert!(!is_valid_person("905661833"));
+        assert!(is_valid_org("905661833"));
+        assert!(!is_valid_org("16057902284"));
+    }
+    #[test]
+    fn test_classify() {
+        assert_eq!(classify("16057902284"), Some(TinShape::Person));
+        assert_eq!(classify("70887100797"), Some(TinShape::Person));
+        // The shape is judged without the check digits
+        assert_eq!(classify("16057902285"), Some(TinShape::Person));
+        assert_eq!(classify("905661833"), Some(TinShape::Org));
+        assert_eq!(classify("201234567800"), Some(TinShape::Duf));
+        assert_eq!(classify("32057902284"), None);
+        assert_eq!(classify("16137902284"), None);
+        assert_eq!(classify("16257902284"), None);
+        assert_eq!(classify("1605790228a"), None);
+        assert_eq!(classify("1234"), None);
+        assert_eq!(classify(""), None);
+    }
+    #[test]
+    fn test_tin_ref() {
+        let inputs = [
+            "16057902284",
+            "70887100797",
+            "22517149261",
+            "905661833",
+            "315543665",
+            "201234567800",
+        ];
+        for input in inputs {
+            let tin = NorwegianTinRef::parse(input).unwrap();
+            assert!(std::ptr::eq(tin.as_str(), input));
+            let owned = NorwegianTin::parse(input).unwrap();
+            assert_eq!(tin.tin_kind(), owned.tin_kind());
+            assert_eq!(tin.to_tin(), owned);
+            assert_eq!(tin.to_string(), owned.to_string());
+        }
+        let invalid = [
+            "16057902285",
+            "160579 02284",
+            "30025060020",
+            "97476067a",
+            "974760674",
+            "20123456780x",
+            "201234567801",
+            "1234",
+        ];
+        for input in invalid {
+            assert_eq!(
+                NorwegianTinRef::parse(input),
+                Err(NorwegianTin::parse(input).unwrap_err()),
+                "{}",
+                input
+            );
+        }
+    }
+    #[test]
+    fn test_try_from() {
+        let tin = NorwegianTin::parse("16057902284").unwrap();
+        assert_eq!(NorwegianTin::try_from("16057902284"), Ok(tin));
+        assert_eq!(NorwegianTin::try_from("16057902284".to_string()), Ok(tin));
+        assert_eq!(
+            NorwegianTin::try_from([1, 6, 0, 5, 7, 9, 0, 2, 2, 8, 4]),
+            Ok(tin)
+        );
+        assert_eq!(
+            NorwegianTin::try_from([1, 6, 0, 5, 7, 9, 0, 2, 2, 8, 5]).map_err(|e| e.kind()),
+            Err(ErrorKind::InvalidChecksum)
+        );
+        assert_eq!(
+            NorwegianTin::try_from([1, 6, 0, 5, 7, 9, 0, 2, 2, 8, 10]),
+            Err(NorwegianTinError::NonNumericValue { position: 10 })
+        );
+        assert_eq!(
+            NorwegianTin::try_from([9, 7, 4, 7, 6, 0, 6, 7, 3]),
+            NorwegianTin::parse("974760673")
+        );
+        assert!(NorwegianTin::try_from("12345").is_err());
+    }
+    #[test]
+    fn test_from_number() {
+        assert_eq!(
+            NorwegianTin::from_number(6071732280, NumberWidth::Person),
+            NorwegianTin::parse("06071732280")
+        );
+        assert_eq!(
+            NorwegianTin::from_number(974760673, NumberWidth::Org),
+            NorwegianTin::parse("974760673")
+        );
+        assert_eq!(
+            NorwegianTin::from_number(201234567800, NumberWidth::Duf),
+            NorwegianTin::parse("201234567800")
+        );
+        assert!(NorwegianTin::from_number(974760673, NumberWidth::Person).is_err());
+        assert_eq!(
+            NorwegianTin::from_number(16057902284, NumberWidth::Org),
+            Err(NorwegianTinError::InvalidLength { found: 11 })
+        );
+    }
+    #[test]
+    fn test_new_unchecked() {
+        let tins = vec!["16057902284", "70887100797", "08639815316", "22517149261"];
+        for tin in tins {
+            let parsed = NorwegianTin::parse(tin).unwrap();
+            let mut digits = [0u8; TIN_LENGTH];
+            digits.copy_from_slice(parsed.value());
+            assert_eq!(NorwegianTin::new_unchecked(digits), parsed);
+        }
+        let org = NorwegianTin::parse("905661833").unwrap();
+        assert_eq!(
+            NorwegianTin::OrgNumber(OrgNumber::new_unchecked([9, 0, 5, 6, 6, 1, 8, 3, 3])),
+            org
+        );
+    }
+    #[test]
+    fn test_org_from_digits() {
+        let org = OrgNumber::from_digits([9, 0, 5, 6, 6, 1, 8, 3, 3]).unwrap();
+        assert_eq!(
+            NorwegianTin::OrgNumber(org),
+            NorwegianTin::parse("905661833").unwrap()
+        );
+        assert_eq!(
+            OrgNumber::from_digits([9, 0, 5, 6, 6, 1, 8, 3, 4])
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidChecksum
+        );
+        assert_eq!(
+            OrgNumber::from_digits([9, 0, 5, 6, 6, 1, 8, 3, 10])
+                .unwrap_err()
+                .kind(),
+            ErrorKind::NonNumericValue
+        );
+    }
+    #[test]
+    fn test_org_complete() {
+        let org = OrgNumber::complete([9, 0, 5, 6, 6, 1, 8, 3]).unwrap();
+        assert_eq!(org.to_digit_string(), "905661833");
+        assert_eq!(
+            OrgNumber::complete([9, 7, 4, 7, 6, 0, 6, 7])
+                .unwrap()
+                .base_digits(),
+            [9, 7, 4, 7, 6, 0, 6, 7]
+        );
+        assert_eq!(
+            OrgNumber::complete([9, 0, 5, 6, 6, 1, 8, 5]),
+            Err(NorwegianTinError::InvalidChecksum {
+                position: 8,
+                expected: None,
+                found: 0,
+            })
+        );
+        assert_eq!(
+            OrgNumber::complete([9, 0, 5, 6, 6, 1, 8, 10])
+                .unwrap_err()
+                .kind(),
+            ErrorKind::NonNumericValue
+        );
+    }
+    #[test]
+    fn test_duf_number() {
+        let dufs = vec!["201234567800", "200100000105", "199987654308"];
+        for duf in dufs {
+            assert!(matches!(
+                NorwegianTin::parse(duf).unwrap(),
+                NorwegianTin::DufNumber(_)
+            ));
+            assert!(is_valid(duf));
+            assert_eq!(
+                NorwegianTin::parse_person(duf).unwrap_err(),
+                NorwegianTinError::WrongIdentifierType
+            );
+        }
+        let invalid = vec!["201234567801", "200100000115", "123456789012"];
+        for duf in invalid {
+            assert_eq!(
+                NorwegianTin::parse_duf(duf).unwrap_err().kind(),
+                ErrorKind::InvalidChecksum
+            );
+        }
+        assert_eq!(
+            NorwegianTin::parse_duf("905661833").unwrap_err(),
+            NorwegianTinError::WrongIdentifierType
+        );
+    }
+    #[test]
+    fn test_synthetic_org_number() {
+        let orgs = vec!["255399985", "331370207", "350759131", "310958352"];
+        for org in orgs {
+            let tin = NorwegianTin::parse(org).unwrap();
+            assert_eq!(tin.kind(), PersonKind::Synthetic);
+            assert!(tin.kind().is_test_id());
+        }
+        let orgs = vec!["905661833", "973289829", "085649779", "406099474"];
+        for org in orgs {
+            let tin = NorwegianTin::parse(org).unwrap();
+            assert_eq!(tin.kind(), PersonKind::Normal);
+            assert!(!tin.kind().is_test_id());
+        }
+    }
+    #[test]
+    fn test_strict_org_prefix() {
+        let strict = ParseOptions::new().strict_org_prefix(true);
+        let orgs = vec!["905661833", "882897311"];
+        for org in orgs {
+            assert!(NorwegianTin::parse_with_options(org, &strict).is_ok());
+        }
+        let orgs = vec!["085649779", "255399985", "406099474"];
+        for org in orgs {
+            assert!(NorwegianTin::parse_with_options(org, &ParseOptions::default()).is_ok());
+            assert_eq!(
+                NorwegianTin::parse_with_options(org, &strict).unwrap_err(),
+                NorwegianTinError::InvalidPrefix
+            );
+        }
+        assert!(NorwegianTin::parse_with_options("16057902284", &strict).is_ok());
+    }
+    #[test]
+    fn test_reject_born_after() {
+        let options = ParseOptions::new().reject_born_after(Date::new(2001, 1, 1).unwrap());
+        let tins = vec![
+            ("16057902284", Ok(())),
+            ("01010150074", Ok(())),
+            ("29020050088", Ok(())),
+            (
+                "05020550084",
+                Err(NorwegianTinError::FutureBirthDate {
+                    birth_date: Date::new(2005, 2, 5).unwrap(),
+                }),
+            ),
+            ("11010000019", Ok(())),
+            ("974760673", Ok(())),
+        ];
+        for (tin, expected) in tins {
+            assert_eq!(
+                NorwegianTin::parse_with_options(tin, &options).map(|_| ()),
+                expected
+            );
+            assert!(NorwegianTin::parse_with_options(tin, &ParseOptions::default()).is_ok());
+        }
+    }
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_date_from_days_since_epoch() {
+        let dates = vec![
+            (0, "1970-01-01"),
+            (59, "1970-03-01"),
+            (10_956, "1999-12-31"),
+            (11_016, "2000-02-29"),
+            (20_742, "2026-10-16"),
+        ];
+        for (days, expected) in dates {
+            assert_eq!(Date::from_days_since_epoch(days).to_string(), expected);
+        }
+        assert!(Date::today() >= Date::new(2026, 1, 1).unwrap());
+    }
+    #[test]
+    fn test_birth_date() {
+        let tins = vec![
+            ("16057902284", Some("1979-05-16")),
+            ("22517149261", Some("1971-11-22")),
+            ("08639815316", Some("1998-03-08")),
+            ("70887100797", Some("1971-08-30")),
+            ("01010150074", Some("2001-01-01")),
+            ("905661833", None),
+            ("201234567800", None),
+        ];
+        for (tin, date) in tins {
+            let tin = NorwegianTin::parse(tin).unwrap();
+            assert_eq!(
+                tin.birth_date().map(|d| d.to_string()),
+                date.map(String::from)
             );
         }
     }
+
+    #[test]
+    fn test_date() {
+        assert!(Date::new(2000, 2, 29).is_ok());
+        assert!(Date::new(1996, 2, 29).is_ok());
+        assert_eq!(
+            Date::new(1900, 2, 29).unwrap_err().kind(),
+            ErrorKind::InvalidDate
+        );
+        assert_eq!(
+            Date::new(2021, 4, 31).unwrap_err().kind(),
+            ErrorKind::InvalidDate
+        );
+        assert!(Date::new(1999, 12, 31).unwrap() < Date::new(2000, 1, 1).unwrap());
+    }
+
+    #[test]
+    fn test_tin_trait() {
+        fn describe<T: Tin>(tin: &T) -> (String, String, bool) {
+            (tin.canonical_string(), tin.masked(), tin.is_test_id())
+        }
+        let tin = NorwegianTin::parse("16057902284").unwrap();
+        assert_eq!(
+            describe(&tin),
+            ("16057902284".to_string(), "160579*****".to_string(), false)
+        );
+        let tin = NorwegianTin::parse("70887100797").unwrap();
+        assert!(describe(&tin).2);
+        assert_eq!(Tin::kind(&tin), PersonKind::Synthetic);
+    }
 }
@@ -0,0 +1,163 @@
+use crate::{NorwegianTin, NorwegianTinError, TIN_LENGTH};
+
+type Checksum = Box<dyn Fn(&[u8; TIN_LENGTH]) -> bool + Send + Sync>;
+
+/// A local 11 digit numbering scheme, such as the hjelpenummer series of a
+/// hospital, recognised by the leading digits and a checksum of its own.
+pub struct LocalScheme {
+    name: String,
+    prefixes: Vec<(String, String)>,
+    checksum: Checksum,
+}
+
+impl LocalScheme {
+    /// A scheme accepting every 11 digit input whose digits, each `0..=9`,
+    /// pass `checksum`. Narrow it with `prefix_range`.
+    pub fn new<F>(name: &str, checksum: F) -> Self
+    where
+        F: Fn(&[u8; TIN_LENGTH]) -> bool + Send + Sync + 'static,
+    {
+        LocalScheme {
+            name: name.to_string(),
+            prefixes: Vec::new(),
+            checksum: Box::new(checksum),
+        }
+    }
+
+    /// Only accepts inputs starting with digits from `start` to `end`, both
+    /// included and of the same length, e.g. `("90", "94")`. Ranges add up.
+    ///
+    /// Panics if the bounds are not digits of the same length.
+    pub fn prefix_range(mut self, start: &str, end: &str) -> Self {
+        assert!(
+            start.len() == end.len()
+                && start.len() <= TIN_LENGTH
+                && start.bytes().chain(end.bytes()).all(|b| b.is_ascii_digit()),
+            "prefix bounds must be digits of the same length"
+        );
+        self.prefixes.push((start.to_string(), end.to_string()));
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn matches(&self, s: &str, digits: &[u8; TIN_LENGTH]) -> bool {
+        // Both bounds are digits of the same length, so comparing the strings
+        // compares the numbers
+        let in_range = self.prefixes.is_empty()
+            || self.prefixes.iter().any(|(start, end)| {
+                let prefix = &s[..start.len()];
+                start.as_str() <= prefix && prefix <= end.as_str()
+            });
+        in_range && (self.checksum)(digits)
+    }
+}
+
+impl std::fmt::Debug for LocalScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalScheme")
+            .field("name", &self.name)
+            .field("prefixes", &self.prefixes)
+            .finish()
+    }
+}
+
+/// A national number, or a number of one of the local schemes of a
+/// `Registry`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Identifier<'a> {
+    National(NorwegianTin),
+    Local {
+        /// The name of the scheme that accepted the number.
+        scheme: &'a str,
+        /// The digits, each `0..=9`.
+        value: [u8; TIN_LENGTH],
+    },
+}
+
+/// The local schemes an application accepts next to the national numbers.
+#[derive(Debug, Default)]
+pub struct Registry {
+    schemes: Vec<LocalScheme>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `scheme`, consulted after the schemes registered before it.
+    pub fn register(&mut self, scheme: LocalScheme) -> &mut Self {
+        self.schemes.push(scheme);
+        self
+    }
+
+    /// Parses like `NorwegianTin::parse`, and when that fails, tries the local
+    /// schemes on 11 digit inputs. If none accepts the input, the error of the
+    /// national parse is returned.
+    pub fn parse(&self, s: &str) -> Result<Identifier<'_>, NorwegianTinError> {
+        let err = match NorwegianTin::parse(s) {
+            Ok(tin) => return Ok(Identifier::National(tin)),
+            Err(err) => err,
+        };
+        if s.len() != TIN_LENGTH || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(err);
+        }
+        let mut value = [0u8; TIN_LENGTH];
+        for (d, b) in value.iter_mut().zip(s.bytes()) {
+            *d = b - b'0';
+        }
+        self.schemes
+            .iter()
+            .find(|scheme| scheme.matches(s, &value))
+            .map(|scheme| Identifier::Local {
+                scheme: &scheme.name,
+                value,
+            })
+            .ok_or(err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+
+    /// Digit sum divisible by 10.
+    fn digit_sum(digits: &[u8; TIN_LENGTH]) -> bool {
+        digits.iter().map(|&d| d as u32).sum::<u32>() % 10 == 0
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = Registry::new();
+        registry
+            .register(LocalScheme::new("ous", digit_sum).prefix_range("90", "92"))
+            .register(LocalScheme::new("any", |digits| digits[10] == 7));
+
+        assert_eq!(
+            registry.parse("16057902284"),
+            Ok(Identifier::National(
+                NorwegianTin::parse("16057902284").unwrap()
+            ))
+        );
+        assert_eq!(
+            registry.parse("91000000000"),
+            Ok(Identifier::Local {
+                scheme: "ous",
+                value: [9, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            })
+        );
+        // Outside the prefix range of the first scheme
+        assert!(matches!(
+            registry.parse("93000000007"),
+            Ok(Identifier::Local { scheme: "any", .. })
+        ));
+        assert_eq!(
+            registry.parse("93000000008"),
+            Err(NorwegianTin::parse("93000000008").unwrap_err())
+        );
+        assert!(registry.parse("9300000000").is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{ErrorKind, NorwegianTinError, TinKind};
+
+/// Language of the end-user messages returned by `NorwegianTinError::message`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Locale {
+    /// Norwegian Bokmål.
+    Bokmal,
+    English,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::Bokmal
+    }
+}
+
+impl NorwegianTinError {
+    /// A sentence describing the error to end users, e.g. next to a form field.
+    pub fn message(&self, locale: Locale) -> &'static str {
+        if let NorwegianTinError::NotAccepted { found } = self {
+            return not_accepted(*found, locale);
+        }
+        match locale {
+            Locale::Bokmal => match self.kind() {
+                ErrorKind::InvalidLength => "Nummeret har feil antall siffer",
+                ErrorKind::NonNumericValue => "Nummeret kan bare inneholde siffer",
+                ErrorKind::InvalidChecksum => "Ugyldig kontrollsiffer",
+                ErrorKind::InvalidDate => "Nummeret inneholder en ugyldig dato",
+                ErrorKind::WrongIdentifierType => "Feil type nummer",
+                ErrorKind::InvalidPrefix => "Nummeret har ugyldig prefiks",
+                ErrorKind::FutureBirthDate => "Fødselsdatoen kan ikke være frem i tid",
+                ErrorKind::NotAccepted => "Denne typen nummer godtas ikke her",
+            },
+            Locale::English => match self.kind() {
+                ErrorKind::InvalidLength => "The number has the wrong number of digits",
+                ErrorKind::NonNumericValue => "The number may only contain digits",
+                ErrorKind::InvalidChecksum => "Invalid check digit",
+                ErrorKind::InvalidDate => "The number contains an invalid date",
+                ErrorKind::WrongIdentifierType => "Wrong type of number",
+                ErrorKind::InvalidPrefix => "The number has an invalid prefix",
+                ErrorKind::FutureBirthDate => "The birth date cannot be in the future",
+                ErrorKind::NotAccepted => "This type of number is not accepted here",
+            },
+        }
+    }
+}
+
+fn not_accepted(found: TinKind, locale: Locale) -> &'static str {
+    match (locale, found) {
+        (Locale::Bokmal, TinKind::FNumber) => "Fødselsnummer godtas ikke her",
+        (Locale::Bokmal, TinKind::DNumber) => "D-nummer godtas ikke her",
+        (Locale::Bokmal, TinKind::OrgNumber) => "Organisasjonsnummer godtas ikke her",
+        (Locale::Bokmal, TinKind::DufNumber) => "DUF-nummer godtas ikke her",
+        (Locale::English, TinKind::FNumber) => "F-numbers are not accepted here",
+        (Locale::English, TinKind::DNumber) => "D-numbers are not accepted here",
+        (Locale::English, TinKind::OrgNumber) => "Organisation numbers are not accepted here",
+        (Locale::English, TinKind::DufNumber) => "DUF numbers are not accepted here",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use crate::NorwegianTin;
+
+    #[test]
+    fn test_message() {
+        let err = NorwegianTin::parse("16057902285").unwrap_err();
+        assert_eq!(err.message(Locale::Bokmal), "Ugyldig kontrollsiffer");
+        assert_eq!(err.message(Locale::English), "Invalid check digit");
+        assert_eq!(err.message(Locale::default()), "Ugyldig kontrollsiffer");
+
+        let err = NorwegianTin::parse("1605790228").unwrap_err();
+        assert_eq!(
+            err.message(Locale::English),
+            "The number has the wrong number of digits"
+        );
+
+        let err = NorwegianTin::parse_with("974760673", crate::Accept::PERSON).unwrap_err();
+        assert_eq!(
+            err.message(Locale::Bokmal),
+            "Organisasjonsnummer godtas ikke her"
+        );
+        assert_eq!(
+            err.message(Locale::English),
+            "Organisation numbers are not accepted here"
+        );
+
+        let err = crate::iban::Iban::parse("SE9386011117947").unwrap_err();
+        assert_eq!(err.message(Locale::Bokmal), "Nummeret har ugyldig prefiks");
+        assert_eq!(
+            err.message(Locale::English),
+            "The number has an invalid prefix"
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::generate::check_digit;
+use crate::{NorwegianTin, SEQUENCE_FIRST_CHECKSUM_DIGITS, SEQUENCE_SECOND_CHECKSUM_DIGITS};
+
+/// `tin` with its last check digit changed, so it fails with
+/// `InvalidChecksum` and nothing else.
+pub fn corrupt_checksum(tin: &NorwegianTin) -> String {
+    let mut digits = tin.value().to_vec();
+    let last = digits.len() - 1;
+    digits[last] = (digits[last] + 1) % 10;
+    to_string(&digits)
+}
+
+/// A person number with the same month, year and individual number as `tin`
+/// but a day past 31, with check digits recomputed so it fails with
+/// `InvalidDate` only. `None` for organisation and DUF numbers, and in the
+/// rare case that no such day has valid check digits.
+pub fn corrupt_date(tin: &NorwegianTin) -> Option<String> {
+    let person = tin.as_person()?;
+    let tens = if tin.is_d_number() { 7 } else { 3 };
+    (2..=9).find_map(|ones| {
+        let mut digits = person.value;
+        digits[0] = tens;
+        digits[1] = ones;
+        digits[9] = 0;
+        digits[10] = 0;
+        digits[9] = check_digit(SEQUENCE_FIRST_CHECKSUM_DIGITS, &digits)?;
+        digits[10] = check_digit(SEQUENCE_SECOND_CHECKSUM_DIGITS, &digits)?;
+        Some(to_string(&digits))
+    })
+}
+
+/// `tin` with the digits at `i` and `j` swapped. `None` when an index is out
+/// of range or the swap leaves a valid number, e.g. when both digits are
+/// equal.
+pub fn swap_digits(tin: &NorwegianTin, i: usize, j: usize) -> Option<String> {
+    let mut digits = tin.value().to_vec();
+    if i >= digits.len() || j >= digits.len() {
+        return None;
+    }
+    digits.swap(i, j);
+    let s = to_string(&digits);
+    match NorwegianTin::parse(&s) {
+        Ok(_) => None,
+        Err(_) => Some(s),
+    }
+}
+
+fn to_string(digits: &[u8]) -> String {
+    digits.iter().map(|&d| (d + b'0') as char).collect()
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use crate::{ErrorKind, NorwegianTinError};
+
+    #[test]
+    fn test_corrupt_checksum() {
+        for input in ["16057902284", "70887100797", "905661833", "201234567800"] {
+            let tin = NorwegianTin::parse(input).unwrap();
+            let corrupted = corrupt_checksum(&tin);
+            assert_eq!(corrupted.len(), input.len());
+            assert_eq!(corrupted[..input.len() - 1], input[..input.len() - 1]);
+            assert_eq!(
+                NorwegianTin::parse(&corrupted).unwrap_err().kind(),
+                ErrorKind::InvalidChecksum
+            );
+        }
+    }
+
+    #[test]
+    fn test_corrupt_date_and_swap() {
+        for input in ["16057902284", "70887100797", "08639815316"] {
+            let tin = NorwegianTin::parse(input).unwrap();
+            let corrupted = corrupt_date(&tin).unwrap();
+            assert_eq!(corrupted[2..9], input[2..9]);
+            assert_eq!(
+                NorwegianTin::parse(&corrupted).unwrap_err().kind(),
+                ErrorKind::InvalidDate
+            );
+        }
+        assert_eq!(
+            corrupt_date(&NorwegianTin::parse("905661833").unwrap()),
+            None
+        );
+
+        let tin = NorwegianTin::parse("16057902284").unwrap();
+        assert_eq!(swap_digits(&tin, 0, 1).as_deref(), Some("61057902284"));
+        assert!(matches!(
+            NorwegianTin::parse("61057902284"),
+            Err(NorwegianTinError::InvalidChecksum { .. })
+        ));
+        // Both digits are 2
+        assert_eq!(swap_digits(&tin, 7, 8), None);
+        assert_eq!(swap_digits(&tin, 0, 11), None);
+    }
+}
@@ -0,0 +1,297 @@
+use std::io;
+
+use serde_json::Value;
+
+use crate::{NorwegianTin, NorwegianTinError};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NdjsonOptions {
+    field: String,
+    trim: bool,
+    skip_empty: bool,
+}
+
+impl NdjsonOptions {
+    /// Validates the value at `field` in each object: a key of the top level
+    /// object, or a JSON pointer such as "/customer/tin" for nested values.
+    pub fn new(field: &str) -> Self {
+        NdjsonOptions {
+            field: field.to_string(),
+            trim: true,
+            skip_empty: false,
+        }
+    }
+
+    /// Trims surrounding whitespace before validating. On by default.
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Leaves out objects whose value is missing, `null` or empty instead of
+    /// reporting them as invalid.
+    pub fn skip_empty(mut self, skip: bool) -> Self {
+        self.skip_empty = skip;
+        self
+    }
+}
+
+/// The validation outcome of a single line.
+#[derive(Debug, PartialEq)]
+pub struct LineOutcome {
+    /// The line in the input, counting from 1.
+    pub line: u64,
+    pub value: String,
+    pub result: Result<NorwegianTin, NorwegianTinError>,
+}
+
+/// A line that could not be validated. It is still written out, with
+/// `tin_valid` `null` and the name of the problem as `tin_error`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LineProblem {
+    /// The line is not valid JSON.
+    InvalidJson,
+    /// The line is valid JSON, but not an object.
+    NotAnObject,
+    /// The value of the field is not a string or a number.
+    UnexpectedType,
+}
+
+impl std::fmt::Display for LineProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LineProblem::InvalidJson => write!(f, "InvalidJson"),
+            LineProblem::NotAnObject => write!(f, "NotAnObject"),
+            LineProblem::UnexpectedType => write!(f, "UnexpectedType"),
+        }
+    }
+}
+
+/// The validation outcome of every line that was not left out by
+/// `skip_empty`.
+#[derive(Debug, PartialEq, Default)]
+pub struct NdjsonReport {
+    pub outcomes: Vec<LineOutcome>,
+    /// Lines that could not be validated, counting from 1.
+    pub problems: Vec<(u64, LineProblem)>,
+}
+
+#[derive(Debug)]
+pub enum NdjsonError {
+    Io(io::Error),
+}
+
+impl std::fmt::Display for NdjsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NdjsonError::Io(err) => write!(f, "Io({})", err),
+        }
+    }
+}
+
+impl std::error::Error for NdjsonError {}
+
+impl From<io::Error> for NdjsonError {
+    fn from(err: io::Error) -> Self {
+        NdjsonError::Io(err)
+    }
+}
+
+/// Validates the field of every object in `reader`, one JSON object per
+/// line, and writes each object to `writer` with two extra keys: `tin_valid`
+/// (`true`/`false`) and `tin_error` (the error name, `null` for valid
+/// values). Objects left out by `skip_empty` are copied with both keys
+/// `null`. Blank lines are skipped. Numbers are validated by their digits, so
+/// person numbers that lost their leading zero are invalid.
+///
+/// A line that cannot be validated does not stop the run: it is reported in
+/// `NdjsonReport::problems` and written with `tin_valid` `null` and the
+/// `LineProblem` as `tin_error`. Lines that are not objects are wrapped as
+/// `{"line": "<the line>", ...}` so the output stays valid NDJSON. Only I/O
+/// errors end the run.
+pub fn annotate_ndjson<R: io::BufRead, W: io::Write>(
+    reader: R,
+    writer: &mut W,
+    options: &NdjsonOptions,
+) -> Result<NdjsonReport, NdjsonError> {
+    let mut report = NdjsonReport::default();
+    for (index, line) in reader.lines().enumerate() {
+        let line_number = index as u64 + 1;
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut object = match serde_json::from_str::<Value>(&line) {
+            Ok(object) if object.is_object() => object,
+            parsed => {
+                let problem = match parsed {
+                    Ok(_) => LineProblem::NotAnObject,
+                    Err(_) => LineProblem::InvalidJson,
+                };
+                report.problems.push((line_number, problem));
+                let mut wrapped = serde_json::Map::new();
+                wrapped.insert(String::from("line"), Value::String(line));
+                let mut wrapped = Value::Object(wrapped);
+                annotate(
+                    &mut wrapped,
+                    Value::Null,
+                    Value::String(problem.to_string()),
+                );
+                write_line(writer, &wrapped)?;
+                continue;
+            }
+        };
+        let (valid, error) = match validate_object(&object, line_number, options) {
+            Ok(Some(outcome)) => {
+                let annotation = match &outcome.result {
+                    Ok(_) => (Value::Bool(true), Value::Null),
+                    Err(err) => (Value::Bool(false), Value::String(err.to_string())),
+                };
+                report.outcomes.push(outcome);
+                annotation
+            }
+            Ok(None) => (Value::Null, Value::Null),
+            Err(problem) => {
+                report.problems.push((line_number, problem));
+                (Value::Null, Value::String(problem.to_string()))
+            }
+        };
+        annotate(&mut object, valid, error);
+        write_line(writer, &object)?;
+    }
+    writer.flush()?;
+    Ok(report)
+}
+
+fn annotate(object: &mut Value, valid: Value, error: Value) {
+    if let Value::Object(fields) = object {
+        fields.insert(String::from("tin_valid"), valid);
+        fields.insert(String::from("tin_error"), error);
+    }
+}
+
+fn write_line<W: io::Write>(writer: &mut W, object: &Value) -> Result<(), NdjsonError> {
+    serde_json::to_writer(&mut *writer, object).map_err(io::Error::from)?;
+    writer.write_all(b"\n")?;
+    Ok(())
+}
+
+fn validate_object(
+    object: &Value,
+    line: u64,
+    options: &NdjsonOptions,
+) -> Result<Option<LineOutcome>, LineProblem> {
+    let field = if options.field.starts_with('/') {
+        object.pointer(&options.field)
+    } else {
+        object.get(&options.field)
+    };
+    let raw = match field {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Number(n)) => n.to_string(),
+        Some(_) => return Err(LineProblem::UnexpectedType),
+    };
+    let value = if options.trim { raw.trim() } else { &raw };
+    if options.skip_empty && value.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(LineOutcome {
+        line,
+        value: value.to_string(),
+        result: NorwegianTin::parse(value),
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use crate::ErrorKind;
+
+    const INPUT: &str = r#"{"name":"Ada","tin":" 16057902284"}
+{"name":"Bob","tin":"12345678901"}
+
+{"name":"Eve"}
+{"name":"Acme","tin":905661833}
+"#;
+
+    #[test]
+    fn test_annotate_ndjson() {
+        let mut output = Vec::new();
+        let options = NdjsonOptions::new("tin").skip_empty(true);
+        let report = annotate_ndjson(INPUT.as_bytes(), &mut output, &options).unwrap();
+        assert!(report.problems.is_empty());
+        let outcomes = report.outcomes;
+        assert_eq!(outcomes.len(), 3);
+        assert_eq!(outcomes[0].value, "16057902284");
+        assert_eq!(
+            outcomes[1].result.as_ref().unwrap_err().kind(),
+            ErrorKind::InvalidChecksum
+        );
+        assert_eq!(outcomes[2].line, 5);
+        assert!(outcomes[2].result.is_ok());
+        // Compared as values, since the key order depends on the features of
+        // serde_json
+        let lines: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                serde_json::json!({"name": "Ada", "tin": " 16057902284", "tin_valid": true, "tin_error": null}),
+                serde_json::json!({"name": "Bob", "tin": "12345678901", "tin_valid": false, "tin_error": "InvalidChecksum"}),
+                serde_json::json!({"name": "Eve", "tin_valid": null, "tin_error": null}),
+                serde_json::json!({"name": "Acme", "tin": 905661833, "tin_valid": true, "tin_error": null}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_annotate_ndjson_pointer() {
+        let input = "{\"customer\":{\"ids\":[\"16057902284\"]}}\n{\"customer\":{}}\n";
+        let options = NdjsonOptions::new("/customer/ids/0");
+        let outcomes = annotate_ndjson(input.as_bytes(), &mut io::sink(), &options)
+            .unwrap()
+            .outcomes;
+        assert!(outcomes[0].result.is_ok());
+        assert_eq!(
+            outcomes[1].result,
+            Err(NorwegianTinError::InvalidLength { found: 0 })
+        );
+    }
+
+    #[test]
+    fn test_annotate_ndjson_bad_lines() {
+        let input = "{\"tin\":\n[1]\n{\"tin\":true}\n{\"tin\":\"905661833\"}\n";
+        let mut output = Vec::new();
+        let report =
+            annotate_ndjson(input.as_bytes(), &mut output, &NdjsonOptions::new("tin")).unwrap();
+        assert_eq!(
+            report.problems,
+            vec![
+                (1, LineProblem::InvalidJson),
+                (2, LineProblem::NotAnObject),
+                (3, LineProblem::UnexpectedType),
+            ]
+        );
+        assert_eq!(report.outcomes.len(), 1);
+        assert_eq!(report.outcomes[0].line, 4);
+        assert!(report.outcomes[0].result.is_ok());
+        let lines: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                serde_json::json!({"line": "{\"tin\":", "tin_valid": null, "tin_error": "InvalidJson"}),
+                serde_json::json!({"line": "[1]", "tin_valid": null, "tin_error": "NotAnObject"}),
+                serde_json::json!({"tin": true, "tin_valid": null, "tin_error": "UnexpectedType"}),
+                serde_json::json!({"tin": "905661833", "tin_valid": true, "tin_error": null}),
+            ]
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{NorwegianTin, NorwegianTinError};
+
+/// A character replaced by the digit it is commonly misread as.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Substitution {
+    /// Character index in the input.
+    pub position: usize,
+    pub from: char,
+    pub to: char,
+}
+
+/// A number parsed from OCR output, with the substitutions that were needed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct OcrParse {
+    pub tin: NorwegianTin,
+    pub substitutions: Vec<Substitution>,
+}
+
+impl OcrParse {
+    /// Whether the input was already plain digits.
+    pub fn is_exact(&self) -> bool {
+        self.substitutions.is_empty()
+    }
+}
+
+/// The digit a character is commonly misread as by OCR, if any.
+pub fn ocr_digit(c: char) -> Option<char> {
+    match c {
+        'O' | 'o' => Some('0'),
+        'I' | 'l' => Some('1'),
+        'S' => Some('5'),
+        'B' => Some('8'),
+        _ => None,
+    }
+}
+
+/// Replaces the common OCR misreads O→0, I/l→1, S→5 and B→8, leaving other
+/// characters as they are.
+pub fn normalize(s: &str) -> (String, Vec<Substitution>) {
+    let mut substitutions = Vec::new();
+    let normalized = s
+        .chars()
+        .enumerate()
+        .map(|(position, c)| match ocr_digit(c) {
+            Some(to) => {
+                substitutions.push(Substitution {
+                    position,
+                    from: c,
+                    to,
+                });
+                to
+            }
+            None => c,
+        })
+        .collect();
+    (normalized, substitutions)
+}
+
+/// Parses like `NorwegianTin::parse` after `normalize`, reporting which
+/// substitutions were applied so they can be reviewed. The checksums still
+/// have to match, which catches most wrong substitutions.
+pub fn parse_ocr(s: &str) -> Result<OcrParse, NorwegianTinError> {
+    let (normalized, substitutions) = normalize(s);
+    let tin = NorwegianTin::parse(&normalized)?;
+    Ok(OcrParse { tin, substitutions })
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use crate::ErrorKind;
+
+    #[test]
+    fn test_parse_ocr() {
+        let tests = vec![
+            ("16057902284", vec![]),
+            (
+                "I6O579O2284",
+                vec![(0, 'I', '1'), (2, 'O', '0'), (6, 'O', '0')],
+            ),
+            ("974760673", vec![]),
+            ("97476O673", vec![(5, 'O', '0')]),
+            ("l6057902284", vec![(0, 'l', '1')]),
+            (
+                "2OI2S4S678O8",
+                vec![
+                    (1, 'O', '0'),
+                    (2, 'I', '1'),
+                    (4, 'S', '5'),
+                    (6, 'S', '5'),
+                    (10, 'O', '0'),
+                ],
+            ),
+        ];
+        for (input, expected) in tests {
+            let parsed = parse_ocr(input).unwrap();
+            let substitutions: Vec<_> = parsed
+                .substitutions
+                .iter()
+                .map(|s| (s.position, s.from, s.to))
+                .collect();
+            assert_eq!(substitutions, expected);
+            assert_eq!(parsed.is_exact(), expected.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_parse_ocr_invalid() {
+        assert_eq!(
+            parse_ocr("I6O579O2285").unwrap_err().kind(),
+            ErrorKind::InvalidChecksum
+        );
+        assert_eq!(
+            parse_ocr("16O579O228X").unwrap_err(),
+            NorwegianTinError::NonNumericValue { position: 10 }
+        );
+        assert_eq!(
+            normalize("B-S"),
+            (
+                String::from("8-5"),
+                vec![
+                    Substitution {
+                        position: 0,
+                        from: 'B',
+                        to: '8'
+                    },
+                    Substitution {
+                        position: 2,
+                        from: 'S',
+                        to: '5'
+                    },
+                ]
+            )
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{
+    strip_separators, Accept, Date, NorwegianTin, TinKind, DUF_LENGTH, ORG_LENGTH,
+    SEQUENCE_FIRST_CHECKSUM_DIGITS, SEQUENCE_ORG_CHECKSUM_DIGITS, SEQUENCE_SECOND_CHECKSUM_DIGITS,
+    TIN_LENGTH,
+};
+
+/// Verdict on input that is still being typed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PartialResult {
+    /// The input is the start of at least one valid number.
+    Incomplete,
+    /// No valid number starts with the input.
+    InvalidSoFar,
+    /// The input is a valid number. When longer kinds are accepted, typing
+    /// may continue, e.g. from a 9 digit organisation number to a person number.
+    Complete(NorwegianTin),
+}
+
+/// Judges `s` as typed so far, against every kind of number. Whitespace,
+/// `.` and `-` are ignored.
+///
+/// DUF numbers only carry a checksum, so any short digit prefix could still
+/// become one. Use `validate_partial_with` to also get feedback on the date
+/// and check digits of person numbers.
+pub fn validate_partial(s: &str) -> PartialResult {
+    validate_partial_with(s, Accept::ALL)
+}
+
+/// Judges `s` as typed so far, against the kinds in `accept`.
+pub fn validate_partial_with(s: &str, accept: Accept) -> PartialResult {
+    let compact = strip_separators(s);
+    if !compact.bytes().all(|b| b.is_ascii_digit()) {
+        return PartialResult::InvalidSoFar;
+    }
+    if let Ok(tin) = NorwegianTin::parse_with(&compact, accept) {
+        return PartialResult::Complete(tin);
+    }
+    let digits: Vec<u8> = compact.bytes().map(|b| b - b'0').collect();
+    let possible = is_person_prefix(&digits, accept)
+        || (accept.contains(TinKind::OrgNumber) && is_org_prefix(&digits))
+        || (accept.contains(TinKind::DufNumber) && digits.len() < DUF_LENGTH);
+    if possible {
+        PartialResult::Incomplete
+    } else {
+        PartialResult::InvalidSoFar
+    }
+}
+
+fn is_person_prefix(digits: &[u8], accept: Accept) -> bool {
+    let len = digits.len();
+    if len >= TIN_LENGTH {
+        return false;
+    }
+    let d_number = match digits.first() {
+        None => return accept.contains(TinKind::FNumber) || accept.contains(TinKind::DNumber),
+        Some(0..=3) if accept.contains(TinKind::FNumber) => false,
+        Some(4..=7) if accept.contains(TinKind::DNumber) => true,
+        Some(_) => return false,
+    };
+    let offset = if d_number { 40 } else { 0 };
+    if len >= 2 && !(1..=31).contains(&(digits[0] * 10 + digits[1] - offset)) {
+        return false;
+    }
+    if len < 3 {
+        return true;
+    }
+    let kind = match NorwegianTin::check_kind(digits[2]) {
+        Some(kind) => kind,
+        None => return false,
+    };
+    if len < 4 {
+        return true;
+    }
+    // Every year divisible by four is a leap year in 2000–2099, so no possible
+    // date is rejected before the century is known, and 2000 allows 29 February
+    // before the year is typed
+    let year = match len {
+        4 | 5 => 2000,
+        _ => 2000 + digits[4] as u16 * 10 + digits[5] as u16,
+    };
+    let month = kind.get_base_month(digits[2] * 10 + digits[3]);
+    if Date::new(year, month, digits[0] * 10 + digits[1] - offset).is_err() {
+        return false;
+    }
+    // With nine digits, at least three of the four first check digits allowed
+    // since 2032 are single digits. With ten, the first must fit and the
+    // second must exist.
+    if len == TIN_LENGTH - 1 {
+        let first = weighted_sum(SEQUENCE_FIRST_CHECKSUM_DIGITS, digits);
+        let second = weighted_sum(SEQUENCE_SECOND_CHECKSUM_DIGITS, digits);
+        return first % 11 <= 3 && (11 - second % 11) % 11 != 10;
+    }
+    true
+}
+
+fn is_org_prefix(digits: &[u8]) -> bool {
+    match digits.len() {
+        len if len < ORG_LENGTH - 1 => true,
+        len if len == ORG_LENGTH - 1 => {
+            let sum: u32 = SEQUENCE_ORG_CHECKSUM_DIGITS
+                .iter()
+                .zip(digits.iter())
+                .map(|(&w, &d)| w as u32 * d as u32)
+                .sum();
+            (11 - sum % 11) % 11 != 10
+        }
+        _ => false,
+    }
+}
+
+fn weighted_sum(weights: &[u32], digits: &[u8]) -> u32 {
+    weights
+        .iter()
+        .zip(digits.iter())
+        .map(|(&w, &d)| w * d as u32)
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+
+    #[test]
+    fn test_partial_person() {
+        let tests = vec![
+            ("", PartialResult::Incomplete),
+            ("1", PartialResult::Incomplete),
+            ("16", PartialResult::Incomplete),
+            ("1605 79", PartialResult::Incomplete),
+            ("160579022", PartialResult::Incomplete),
+            ("1605790228", PartialResult::Incomplete),
+            (
+                "16057902284",
+                PartialResult::Complete(NorwegianTin::parse("16057902284").unwrap()),
+            ),
+            ("8", PartialResult::InvalidSoFar),
+            ("32", PartialResult::InvalidSoFar),
+            ("40", PartialResult::InvalidSoFar),
+            ("012", PartialResult::InvalidSoFar),
+            ("0113", PartialResult::InvalidSoFar),
+            ("3004", PartialResult::Incomplete),
+            ("3102", PartialResult::InvalidSoFar),
+            ("290201", PartialResult::InvalidSoFar),
+            ("290204", PartialResult::Incomplete),
+            // First sum leaves 4, which no scheme allows
+            ("1605790227", PartialResult::InvalidSoFar),
+            ("1605790228a", PartialResult::InvalidSoFar),
+            ("16057902285", PartialResult::InvalidSoFar),
+            ("160579022845", PartialResult::InvalidSoFar),
+        ];
+        for (input, expected) in tests {
+            assert_eq!(
+                validate_partial_with(input, Accept::PERSON),
+                expected,
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_partial_any() {
+        let tests = vec![
+            ("8", PartialResult::Incomplete),
+            ("97476067", PartialResult::Incomplete),
+            (
+                "974760673",
+                PartialResult::Complete(NorwegianTin::parse("974760673").unwrap()),
+            ),
+            ("16057902285", PartialResult::Incomplete),
+            ("201234567801", PartialResult::InvalidSoFar),
+            ("1234567890123", PartialResult::InvalidSoFar),
+            ("12-34 x", PartialResult::InvalidSoFar),
+        ];
+        for (input, expected) in tests {
+            assert_eq!(validate_partial(input), expected, "{}", input);
+        }
+        assert_eq!(
+            validate_partial_with("97476067", Accept::ORG_NUMBER),
+            PartialResult::Incomplete
+        );
+        assert_eq!(
+            validate_partial_with("974760673", Accept::PERSON),
+            PartialResult::InvalidSoFar
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use std::str::FromStr;
+
+use crate::{NorwegianTin, NorwegianTinError, OrgNumber};
+
+/// ISO 6523 International Code Designator of Norwegian organisation numbers.
+pub const NORWEGIAN_ORG_ICD: &str = "0192";
+/// Identifier scheme of Peppol participant IDs, as used in SMP lookups.
+pub const PARTICIPANT_SCHEME: &str = "iso6523-actorid-upis";
+
+/// An identifier with the `schemeID` attribute it carries in EHF/UBL
+/// documents, e.g. `<cbc:EndpointID schemeID="0192">977279410</cbc:EndpointID>`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SchemeId {
+    pub scheme_id: &'static str,
+    pub value: String,
+}
+
+impl SchemeId {
+    /// Formats the identifier as a UBL element named `element`, e.g.
+    /// "cbc:EndpointID". The values are digits and fixed strings, so no
+    /// escaping is needed.
+    pub fn to_xml(&self, element: &str) -> String {
+        format!(
+            "<{} schemeID=\"{}\">{}</{}>",
+            element, self.scheme_id, self.value, element
+        )
+    }
+}
+
+/// A Peppol participant identifier for a Norwegian organisation, e.g.
+/// "0192:977279410".
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParticipantId {
+    org: OrgNumber,
+}
+
+impl ParticipantId {
+    /// Parses "0192:977279410", optionally prefixed with the
+    /// "iso6523-actorid-upis::" scheme, validating the organisation number.
+    /// Other ICDs fail with `InvalidPrefix`.
+    pub fn parse(s: &str) -> Result<ParticipantId, NorwegianTinError> {
+        let scheme_prefix = format!("{}::", PARTICIPANT_SCHEME);
+        let offset = match s.get(..scheme_prefix.len()) {
+            Some(prefix) if prefix.eq_ignore_ascii_case(&scheme_prefix) => scheme_prefix.len(),
+            _ => 0,
+        };
+        let icd_prefix = format!("{}:", NORWEGIAN_ORG_ICD);
+        if !s[offset..].starts_with(&icd_prefix) {
+            return Err(NorwegianTinError::InvalidPrefix);
+        }
+        let offset = offset + icd_prefix.len();
+        let tin = NorwegianTin::parse_org(&s[offset..]).map_err(|err| err.shifted(offset))?;
+        match tin {
+            NorwegianTin::OrgNumber(org) => Ok(ParticipantId { org }),
+            _ => Err(NorwegianTinError::WrongIdentifierType),
+        }
+    }
+
+    pub fn org(&self) -> OrgNumber {
+        self.org
+    }
+
+    /// The identifier with its scheme, "iso6523-actorid-upis::0192:977279410".
+    pub fn with_scheme(&self) -> String {
+        format!("{}::{}", PARTICIPANT_SCHEME, self)
+    }
+}
+
+impl OrgNumber {
+    pub fn to_participant_id(&self) -> ParticipantId {
+        ParticipantId { org: *self }
+    }
+
+    /// The `cbc:EndpointID` of the party, with schemeID "0192".
+    pub fn endpoint_id(&self) -> SchemeId {
+        SchemeId {
+            scheme_id: NORWEGIAN_ORG_ICD,
+            value: self.to_digit_string(),
+        }
+    }
+
+    /// The legal entity `cbc:CompanyID`, which uses the same scheme as the
+    /// endpoint.
+    pub fn legal_entity_id(&self) -> SchemeId {
+        self.endpoint_id()
+    }
+
+    /// The VAT number "NO977279410MVA", used as the `cbc:CompanyID` of the
+    /// party tax scheme of VAT registered sellers.
+    pub fn vat_number(&self) -> String {
+        format!("NO{}MVA", self.to_digit_string())
+    }
+}
+
+/// Returns whether `s` is a valid Peppol participant ID of a Norwegian
+/// organisation.
+pub fn is_valid(s: &str) -> bool {
+    ParticipantId::parse(s).is_ok()
+}
+
+impl FromStr for ParticipantId {
+    type Err = NorwegianTinError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl From<OrgNumber> for ParticipantId {
+    fn from(org: OrgNumber) -> ParticipantId {
+        org.to_participant_id()
+    }
+}
+
+impl From<ParticipantId> for String {
+    fn from(id: ParticipantId) -> String {
+        id.to_string()
+    }
+}
+
+impl std::fmt::Display for ParticipantId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", NORWEGIAN_ORG_ICD, self.org)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use crate::ErrorKind;
+
+    #[test]
+    fn test_valid_participant_id() {
+        let ids = vec![
+            "0192:977279410",
+            "iso6523-actorid-upis::0192:977279410",
+            "ISO6523-ACTORID-UPIS::0192:977279410",
+        ];
+        for id in ids {
+            let parsed = ParticipantId::parse(id).unwrap();
+            assert_eq!(parsed.org().to_digit_string(), "977279410");
+            assert_eq!(parsed.to_string(), "0192:977279410");
+            assert!(is_valid(id));
+        }
+    }
+
+    #[test]
+    fn test_invalid_participant_id() {
+        assert_eq!(
+            ParticipantId::parse("0192:977279411").unwrap_err(),
+            NorwegianTinError::InvalidChecksum {
+                position: 13,
+                expected: Some(0),
+                found: 1,
+            }
+        );
+        assert_eq!(
+            ParticipantId::parse("0007:977279410").unwrap_err(),
+            NorwegianTinError::InvalidPrefix
+        );
+        assert_eq!(
+            ParticipantId::parse("977279410").unwrap_err(),
+            NorwegianTinError::InvalidPrefix
+        );
+        assert_eq!(
+            ParticipantId::parse("0192:16057902284").unwrap_err(),
+            NorwegianTinError::WrongIdentifierType
+        );
+        assert_eq!(
+            ParticipantId::parse("0192:97727941").unwrap_err().kind(),
+            ErrorKind::InvalidLength
+        );
+    }
+
+    #[test]
+    fn test_ehf_identifiers() {
+        let tin = NorwegianTin::parse_org("977279410").unwrap();
+        let org = tin.as_org().unwrap();
+        assert_eq!(
+            org.endpoint_id(),
+            SchemeId {
+                scheme_id: "0192",
+                value: String::from("977279410"),
+            }
+        );
+        assert_eq!(
+            org.endpoint_id().to_xml("cbc:EndpointID"),
+            "<cbc:EndpointID schemeID=\"0192\">977279410</cbc:EndpointID>"
+        );
+        assert_eq!(org.legal_entity_id(), org.endpoint_id());
+        assert_eq!(org.vat_number(), "NO977279410MVA");
+        assert!(tin.matches_input(&org.vat_number()));
+    }
+
+    #[test]
+    fn test_from_org() {
+        let tin = NorwegianTin::parse_org("977279410").unwrap();
+        let id = tin.as_org().unwrap().to_participant_id();
+        assert_eq!(String::from(id), "0192:977279410");
+        assert_eq!(id.with_scheme(), "iso6523-actorid-upis::0192:977279410");
+        assert_eq!(ParticipantId::parse(&id.with_scheme()).unwrap(), id);
+    }
+}
@@ -0,0 +1,140 @@
+use std::error::Error;
+
+use bytes::BytesMut;
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::{NorwegianTin, NorwegianTinError, OrgNumber};
+
+type BoxError = Box<dyn Error + Sync + Send>;
+
+/// Writes the digit string of the number to a TEXT, VARCHAR or CHAR column.
+impl ToSql for NorwegianTin {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        String::from(*self).to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+/// Validates the stored text, failing with its `NorwegianTinError`.
+impl<'a> FromSql<'a> for NorwegianTin {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        let value = <&str as FromSql>::from_sql(ty, raw)?;
+        Ok(NorwegianTin::parse(value)?)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as FromSql>::accepts(ty)
+    }
+}
+
+impl ToSql for OrgNumber {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        NorwegianTin::OrgNumber(*self).to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+/// Only organisation numbers are accepted, other valid numbers fail with
+/// `WrongIdentifierType`.
+impl<'a> FromSql<'a> for OrgNumber {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        let value = <&str as FromSql>::from_sql(ty, raw)?;
+        let tin = NorwegianTin::parse_org(value)?;
+        let org = tin.as_org().ok_or(NorwegianTinError::WrongIdentifierType)?;
+        Ok(*org)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as FromSql>::accepts(ty)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tin_round_trip() {
+        let tin = NorwegianTin::parse("06071732280").unwrap();
+        let mut buf = BytesMut::new();
+        assert!(matches!(tin.to_sql(&Type::TEXT, &mut buf), Ok(IsNull::No)));
+        assert_eq!(&buf[..], b"06071732280");
+        assert_eq!(NorwegianTin::from_sql(&Type::VARCHAR, &buf).unwrap(), tin);
+    }
+
+    #[test]
+    fn test_tin_from_sql_null() {
+        assert!(NorwegianTin::from_sql_nullable(&Type::TEXT, None).is_err());
+        assert_eq!(
+            Option::<NorwegianTin>::from_sql_nullable(&Type::TEXT, None).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_to_sql_checked() {
+        let tin = NorwegianTin::parse("06071732280").unwrap();
+        let mut buf = BytesMut::new();
+        assert!(tin.to_sql_checked(&Type::INT8, &mut buf).is_err());
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_tin_from_sql_invalid() {
+        let tests = vec![
+            (
+                "16057902285",
+                NorwegianTinError::InvalidChecksum {
+                    position: 10,
+                    expected: Some(4),
+                    found: 5,
+                },
+            ),
+            ("1605790228", NorwegianTinError::InvalidLength { found: 10 }),
+            (
+                "16057a02284",
+                NorwegianTinError::NonNumericValue { position: 5 },
+            ),
+        ];
+        for (value, expected) in tests {
+            let err = NorwegianTin::from_sql(&Type::TEXT, value.as_bytes()).unwrap_err();
+            assert_eq!(err.downcast_ref::<NorwegianTinError>(), Some(&expected));
+        }
+    }
+
+    #[test]
+    fn test_org_round_trip() {
+        let org = *NorwegianTin::parse_org("974760673")
+            .unwrap()
+            .as_org()
+            .unwrap();
+        let mut buf = BytesMut::new();
+        org.to_sql(&Type::TEXT, &mut buf).unwrap();
+        assert_eq!(&buf[..], b"974760673");
+        assert_eq!(OrgNumber::from_sql(&Type::TEXT, &buf).unwrap(), org);
+
+        let err = OrgNumber::from_sql(&Type::TEXT, b"16057902284").unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<NorwegianTinError>(),
+            Some(&NorwegianTinError::WrongIdentifierType)
+        );
+    }
+
+    #[test]
+    fn test_accepts() {
+        assert!(<NorwegianTin as ToSql>::accepts(&Type::TEXT));
+        assert!(<NorwegianTin as FromSql>::accepts(&Type::VARCHAR));
+        assert!(<OrgNumber as FromSql>::accepts(&Type::BPCHAR));
+        assert!(!<NorwegianTin as FromSql>::accepts(&Type::INT8));
+    }
+}
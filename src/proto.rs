@@ -0,0 +1,135 @@
+// Prost types matching `proto/norwegian_tin.proto`, for gRPC services that
+// exchange identification numbers.
+
+use std::convert::TryFrom;
+
+use crate::{NorwegianTin, NorwegianTinError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TinKind {
+    Unspecified = 0,
+    FNumber = 1,
+    DNumber = 2,
+    OrgNumber = 3,
+    DufNumber = 4,
+}
+
+/// The `Tin` message: the full digit string and the kind of number.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TinMessage {
+    #[prost(string, tag = "1")]
+    pub value: String,
+    #[prost(enumeration = "TinKind", tag = "2")]
+    pub kind: i32,
+}
+
+impl From<crate::TinKind> for TinKind {
+    fn from(kind: crate::TinKind) -> TinKind {
+        match kind {
+            crate::TinKind::FNumber => TinKind::FNumber,
+            crate::TinKind::DNumber => TinKind::DNumber,
+            crate::TinKind::OrgNumber => TinKind::OrgNumber,
+            crate::TinKind::DufNumber => TinKind::DufNumber,
+        }
+    }
+}
+
+impl From<&NorwegianTin> for TinKind {
+    fn from(tin: &NorwegianTin) -> TinKind {
+        TinKind::from(tin.tin_kind())
+    }
+}
+
+impl From<NorwegianTin> for TinMessage {
+    fn from(tin: NorwegianTin) -> TinMessage {
+        TinMessage {
+            kind: TinKind::from(&tin) as i32,
+            value: tin.into(),
+        }
+    }
+}
+
+/// Validates the value, and checks it against the kind unless the kind is
+/// `Unspecified`. Unknown or mismatching kinds fail with `WrongIdentifierType`.
+impl TryFrom<&TinMessage> for NorwegianTin {
+    type Error = NorwegianTinError;
+
+    fn try_from(message: &TinMessage) -> Result<Self, Self::Error> {
+        let tin = NorwegianTin::parse(&message.value)?;
+        match TinKind::try_from(message.kind) {
+            Ok(TinKind::Unspecified) => Ok(tin),
+            Ok(kind) if kind == TinKind::from(&tin) => Ok(tin),
+            _ => Err(NorwegianTinError::WrongIdentifierType),
+        }
+    }
+}
+
+impl TryFrom<TinMessage> for NorwegianTin {
+    type Error = NorwegianTinError;
+
+    fn try_from(message: TinMessage) -> Result<Self, Self::Error> {
+        NorwegianTin::try_from(&message)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use prost::Message;
+
+    #[test]
+    fn test_message_roundtrip() {
+        let tests = vec![
+            ("16057902284", TinKind::FNumber),
+            ("56865400190", TinKind::DNumber),
+            ("974760673", TinKind::OrgNumber),
+            ("201234567800", TinKind::DufNumber),
+        ];
+        for (value, kind) in tests {
+            let tin = NorwegianTin::parse(value).unwrap();
+            let message = TinMessage::from(tin);
+            assert_eq!(message.value, value);
+            assert_eq!(message.kind(), kind);
+
+            let decoded = TinMessage::decode(message.encode_to_vec().as_slice()).unwrap();
+            assert_eq!(decoded, message);
+            assert_eq!(NorwegianTin::try_from(decoded).unwrap(), tin);
+        }
+    }
+
+    #[test]
+    fn test_message_validation() {
+        let tests = vec![
+            ("16057902284", TinKind::Unspecified as i32, Ok(())),
+            ("16057902284", TinKind::FNumber as i32, Ok(())),
+            (
+                "16057902284",
+                TinKind::OrgNumber as i32,
+                Err(NorwegianTinError::WrongIdentifierType),
+            ),
+            (
+                "16057902284",
+                42,
+                Err(NorwegianTinError::WrongIdentifierType),
+            ),
+            (
+                "16057902285",
+                TinKind::FNumber as i32,
+                Err(NorwegianTinError::InvalidChecksum {
+                    position: 10,
+                    expected: Some(4),
+                    found: 5,
+                }),
+            ),
+            ("", 0, Err(NorwegianTinError::InvalidLength { found: 0 })),
+        ];
+        for (value, kind, expected) in tests {
+            let message = TinMessage {
+                value: value.to_string(),
+                kind,
+            };
+            assert_eq!(NorwegianTin::try_from(&message).map(|_| ()), expected);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use rand::distr::{Distribution, StandardUniform};
+use rand::Rng;
+
+use crate::generate::{generate, generate_from_profile, Profile, TinSpec};
+use crate::NorwegianTin;
+
+/// Samples normal F-numbers, like `TinSpec::default()`.
+impl Distribution<NorwegianTin> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NorwegianTin {
+        TinSpec::default().sample(rng)
+    }
+}
+
+impl Distribution<NorwegianTin> for TinSpec {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NorwegianTin {
+        generate(self, &mut |n| rng.random_range(0..n))
+    }
+}
+
+impl Distribution<NorwegianTin> for Profile {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NorwegianTin {
+        generate_from_profile(self, &mut |n| rng.random_range(0..n))
+    }
+}
+
+impl NorwegianTin {
+    /// A random normal F-number from the thread local generator.
+    pub fn random() -> NorwegianTin {
+        rand::rng().random()
+    }
+
+    /// A random number of `spec`, e.g. for test fixtures.
+    pub fn random_with<R: Rng + ?Sized>(rng: &mut R, spec: &TinSpec) -> NorwegianTin {
+        spec.sample(rng)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{PersonKind, TinKind};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_random() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let tin: NorwegianTin = rng.random();
+        assert!(tin.is_f_number());
+        assert!(NorwegianTin::random().is_f_number());
+
+        let spec = TinSpec::new(TinKind::DNumber).person_kind(PersonKind::Synthetic);
+        for _ in 0..100 {
+            let tin = NorwegianTin::random_with(&mut rng, &spec);
+            assert!(tin.is_d_number());
+            assert_eq!(tin.kind(), PersonKind::Synthetic);
+            assert_eq!(NorwegianTin::parse(&tin.canonical_string()), Ok(tin));
+        }
+        let orgs: Vec<NorwegianTin> = (&mut rng)
+            .sample_iter(TinSpec::new(TinKind::OrgNumber))
+            .take(10)
+            .collect();
+        assert!(orgs.iter().all(|tin| tin.is_org_number()));
+        let tin = rng.sample(Profile::norway());
+        assert!(tin.birth_date().unwrap().year() >= 1925);
+    }
+}
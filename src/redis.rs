@@ -0,0 +1,60 @@
+use ::redis::{FromRedisValue, ParsingError, RedisWrite, ToRedisArgs, Value};
+
+use crate::NorwegianTin;
+
+/// Written as the full digit string, the same form the `serde` impls use.
+impl ToRedisArgs for NorwegianTin {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        let s: String = (*self).into();
+        out.write_arg(s.as_bytes());
+    }
+}
+
+/// Read from a string reply and validated again. Integer replies are
+/// rejected.
+impl FromRedisValue for NorwegianTin {
+    fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+        let s = match v {
+            Value::BulkString(bytes) => std::str::from_utf8(bytes)?,
+            Value::SimpleString(s) => s.as_str(),
+            _ => return Err(ParsingError::from("expected a NorwegianTin as a string")),
+        };
+        NorwegianTin::parse(s)
+            .map_err(|err| ParsingError::from(format!("invalid NorwegianTin: {}", err)))
+    }
+
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        Self::from_redis_value_ref(&v)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_to_redis_args() {
+        let tin = NorwegianTin::parse("06071732280").unwrap();
+        assert_eq!(tin.to_redis_args(), vec![b"06071732280".to_vec()]);
+    }
+
+    #[test]
+    fn test_from_redis_value() {
+        let tin = NorwegianTin::parse("06071732280").unwrap();
+        let reply = Value::BulkString(b"06071732280".to_vec());
+        assert_eq!(NorwegianTin::from_redis_value(reply).unwrap(), tin);
+        let reply = Value::SimpleString(String::from("06071732280"));
+        assert_eq!(NorwegianTin::from_redis_value(reply).unwrap(), tin);
+
+        let err =
+            NorwegianTin::from_redis_value(Value::BulkString(b"16057902285".to_vec())).unwrap_err();
+        assert!(err.to_string().contains("InvalidChecksum"));
+        assert!(NorwegianTin::from_redis_value(Value::BulkString(vec![0xff; 11])).is_err());
+        // The integer form of the same number has lost its leading zero
+        assert!(NorwegianTin::from_redis_value(Value::Int(6071732280)).is_err());
+        assert!(NorwegianTin::from_redis_value(Value::Nil).is_err());
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{NorwegianTin, NorwegianTinError, TIN_LENGTH};
+
+/// A number parsed with `parse_repairing`, and whether it had to be repaired.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RepairedParse {
+    pub tin: NorwegianTin,
+    /// The input had 10 digits and was padded with a leading zero.
+    pub leading_zero_added: bool,
+}
+
+/// Parses like `NorwegianTin::parse`, and also accepts person numbers born
+/// on the 1st to the 9th whose leading zero was lost, e.g. in a spreadsheet
+/// that read "06071732280" as a number. A 10 digit input is padded with a
+/// zero and validated as usual, and the repair is flagged so it can be
+/// reviewed. When the padded number is not valid either, the error is the
+/// `InvalidLength` of the input.
+pub fn parse_repairing(s: &str) -> Result<RepairedParse, NorwegianTinError> {
+    let err = match NorwegianTin::parse(s) {
+        Ok(tin) => {
+            return Ok(RepairedParse {
+                tin,
+                leading_zero_added: false,
+            })
+        }
+        Err(err) => err,
+    };
+    if s.len() != TIN_LENGTH - 1 || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(err);
+    }
+    match NorwegianTin::parse_person(&format!("0{}", s)) {
+        Ok(tin) => Ok(RepairedParse {
+            tin,
+            leading_zero_added: true,
+        }),
+        Err(_) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+
+    #[test]
+    fn test_parse_repairing() {
+        let repaired = parse_repairing("6071732280").unwrap();
+        assert!(repaired.leading_zero_added);
+        assert_eq!(repaired.tin, NorwegianTin::parse("06071732280").unwrap());
+
+        let exact = parse_repairing("06071732280").unwrap();
+        assert!(!exact.leading_zero_added);
+        assert_eq!(exact.tin, repaired.tin);
+        assert!(!parse_repairing("974760673").unwrap().leading_zero_added);
+
+        assert_eq!(
+            parse_repairing("6071732281"),
+            Err(NorwegianTinError::InvalidLength { found: 10 })
+        );
+        assert_eq!(
+            parse_repairing("607173228a"),
+            Err(NorwegianTinError::InvalidLength { found: 10 })
+        );
+        assert_eq!(
+            parse_repairing("16057902285").map_err(|e| e.kind()),
+            Err(crate::ErrorKind::InvalidChecksum)
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{
+    ArchivedNorwegianTin, ArchivedPersonKind, ArchivedPersonNumber, DufNumber, NorwegianTin,
+    NorwegianTinError, OrgNumber, PersonKind, PersonNumber,
+};
+
+// Archived numbers hold the same digits as the originals, so they can be read
+// in place from a memory-mapped archive without parsing or deserializing.
+impl ArchivedNorwegianTin {
+    /// The digits of the archived number, like `NorwegianTin::value`.
+    pub fn value(&self) -> &[u8] {
+        match self {
+            ArchivedNorwegianTin::FNumber(fnr) => &fnr.value,
+            ArchivedNorwegianTin::DNumber(dnr) => &dnr.value,
+            ArchivedNorwegianTin::OrgNumber(org) => &org.value,
+            ArchivedNorwegianTin::DufNumber(duf) => &duf.value,
+        }
+    }
+}
+
+impl From<&ArchivedPersonKind> for PersonKind {
+    fn from(kind: &ArchivedPersonKind) -> PersonKind {
+        match kind {
+            ArchivedPersonKind::Normal => PersonKind::Normal,
+            ArchivedPersonKind::HNumber => PersonKind::HNumber,
+            ArchivedPersonKind::Anonymous => PersonKind::Anonymous,
+            ArchivedPersonKind::Synthetic => PersonKind::Synthetic,
+        }
+    }
+}
+
+/// Validates the archived digits like `NorwegianTin::parse`, as the archive
+/// may have been written by another program or been corrupted. The kind is
+/// taken from the digits.
+impl TryFrom<&ArchivedPersonNumber> for PersonNumber {
+    type Error = NorwegianTinError;
+
+    fn try_from(number: &ArchivedPersonNumber) -> Result<PersonNumber, NorwegianTinError> {
+        NorwegianTin::check_person(&to_ascii(&number.value)?)
+    }
+}
+
+/// Validates the archived digits like `NorwegianTin::parse`, failing with
+/// `WrongIdentifierType` when they do not match the archived variant.
+impl TryFrom<&ArchivedNorwegianTin> for NorwegianTin {
+    type Error = NorwegianTinError;
+
+    fn try_from(tin: &ArchivedNorwegianTin) -> Result<NorwegianTin, NorwegianTinError> {
+        match tin {
+            ArchivedNorwegianTin::FNumber(fnr) if fnr.value[0] <= 3 => {
+                Ok(NorwegianTin::FNumber(fnr.try_into()?))
+            }
+            ArchivedNorwegianTin::DNumber(dnr) if dnr.value[0] > 3 => {
+                Ok(NorwegianTin::DNumber(dnr.try_into()?))
+            }
+            ArchivedNorwegianTin::FNumber(_) | ArchivedNorwegianTin::DNumber(_) => {
+                Err(NorwegianTinError::WrongIdentifierType)
+            }
+            ArchivedNorwegianTin::OrgNumber(org) => {
+                Ok(NorwegianTin::OrgNumber(OrgNumber::from_digits(org.value)?))
+            }
+            ArchivedNorwegianTin::DufNumber(duf) => {
+                NorwegianTin::check_duf(&to_ascii(&duf.value)?)?;
+                Ok(NorwegianTin::DufNumber(DufNumber { value: duf.value }))
+            }
+        }
+    }
+}
+
+fn to_ascii<const N: usize>(digits: &[u8; N]) -> Result<[u8; N], NorwegianTinError> {
+    if let Some(position) = digits.iter().position(|&d| d > 9) {
+        return Err(NorwegianTinError::NonNumericValue { position });
+    }
+    Ok(digits.map(|d| d + b'0'))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rkyv::rancor::Error;
+    use rkyv::vec::ArchivedVec;
+
+    #[test]
+    fn test_archive_roundtrip() {
+        let tins: Vec<NorwegianTin> = vec![
+            "16057902284",
+            "56865400190",
+            "70887100797",
+            "974760673",
+            "201234567800",
+        ]
+        .into_iter()
+        .map(|s| NorwegianTin::parse(s).unwrap())
+        .collect();
+
+        let bytes = rkyv::to_bytes::<Error>(&tins).unwrap();
+        let archived = rkyv::access::<ArchivedVec<ArchivedNorwegianTin>, Error>(&bytes).unwrap();
+        assert_eq!(archived.len(), tins.len());
+        for (archived, tin) in archived.iter().zip(&tins) {
+            assert_eq!(archived.value(), tin.value());
+            assert_eq!(NorwegianTin::try_from(archived), Ok(*tin));
+        }
+
+        let deserialized = rkyv::from_bytes::<Vec<NorwegianTin>, Error>(&bytes).unwrap();
+        assert_eq!(deserialized, tins);
+    }
+
+    #[test]
+    fn test_archive_corrupted() {
+        let tins = vec![
+            NorwegianTin::parse("16057902284").unwrap(),
+            NorwegianTin::parse("974760673").unwrap(),
+        ];
+        let mut bytes = rkyv::to_bytes::<Error>(&tins).unwrap();
+        // Flip the check digit of the person number and put a non-digit in the
+        // organisation number
+        let fnr = bytes
+            .windows(11)
+            .position(|w| w == tins[0].value())
+            .unwrap();
+        bytes[fnr + 10] = 5;
+        let org = bytes.windows(9).position(|w| w == tins[1].value()).unwrap();
+        bytes[org + 4] = 10;
+
+        let archived = rkyv::access::<ArchivedVec<ArchivedNorwegianTin>, Error>(&bytes).unwrap();
+        assert_eq!(
+            NorwegianTin::try_from(&archived[0]),
+            Err(NorwegianTinError::InvalidChecksum {
+                position: 10,
+                expected: Some(4),
+                found: 5
+            })
+        );
+        assert_eq!(
+            NorwegianTin::try_from(&archived[1]),
+            Err(NorwegianTinError::NonNumericValue { position: 4 })
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use ::rocket::form::{self, FromFormField, ValueField};
+use ::rocket::request::FromParam;
+
+use crate::{NorwegianTin, NorwegianTinError};
+
+/// Lets routes declare `tin: NorwegianTin` as a dynamic path segment.
+/// Invalid values make the route fail to match with the `NorwegianTinError`.
+impl<'a> FromParam<'a> for NorwegianTin {
+    type Error = NorwegianTinError;
+
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        NorwegianTin::parse(param)
+    }
+}
+
+/// Lets forms and query strings declare `NorwegianTin` fields. Invalid values
+/// are reported as validation errors carrying the error name.
+#[::rocket::async_trait]
+impl<'v> FromFormField<'v> for NorwegianTin {
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        NorwegianTin::parse(field.value)
+            .map_err(|err| form::Error::validation(err.to_string()).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::rocket::form::Form;
+    use ::rocket::http::{ContentType, Status};
+    use ::rocket::local::blocking::Client;
+    use ::rocket::{get, post, routes, FromForm};
+
+    #[get("/persons/<tin>")]
+    fn person(tin: NorwegianTin) -> String {
+        tin.to_string()
+    }
+
+    #[derive(FromForm)]
+    struct Registration {
+        tin: NorwegianTin,
+    }
+
+    #[post("/register", data = "<form>")]
+    fn register(form: Form<Registration>) -> String {
+        form.tin.to_string()
+    }
+
+    #[test]
+    fn test_from_param() {
+        let client = Client::tracked(::rocket::build().mount("/", routes![person])).unwrap();
+        let response = client.get("/persons/16057902284").dispatch();
+        assert_eq!(response.into_string().unwrap(), "160579*****");
+        let response = client.get("/persons/12345678901").dispatch();
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+    }
+
+    #[test]
+    fn test_from_form_field() {
+        let client = Client::tracked(::rocket::build().mount("/", routes![register])).unwrap();
+        let response = client
+            .post("/register")
+            .header(ContentType::Form)
+            .body("tin=70887100797")
+            .dispatch();
+        assert_eq!(response.into_string().unwrap(), " (Synthetic) 708871*****");
+        let response = client
+            .post("/register")
+            .header(ContentType::Form)
+            .body("tin=70887100798")
+            .dispatch();
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{NorwegianTin, DUF_LENGTH};
+
+/// A valid number found by a `Scanner`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Match {
+    /// The byte offset of the first digit from the start of the stream.
+    pub offset: u64,
+    pub tin: NorwegianTin,
+}
+
+/// Finds valid numbers in a stream of bytes pushed in chunks of any size,
+/// such as a file read through a fixed buffer. A candidate is a run of 9, 11
+/// or 12 ASCII digits with no digit directly before or after it, so numbers
+/// split across chunks are found, and digits inside longer runs such as
+/// account numbers are not.
+#[derive(Debug, Default, Clone)]
+pub struct Scanner {
+    /// The digits of the run at the end of the input so far, if it is not
+    /// longer than any number.
+    run: [u8; DUF_LENGTH],
+    run_len: usize,
+    /// The current run is longer than any number.
+    overlong: bool,
+    /// Offset of the start of the current run.
+    run_start: u64,
+    /// Bytes pushed so far.
+    position: u64,
+}
+
+impl Scanner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scans `chunk`, returning the numbers that ended in it. A number that
+    /// ends at the last byte of `chunk` is returned by a later `push` or by
+    /// `finish`, since the next chunk may continue the run.
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<Match> {
+        let mut found = Vec::new();
+        for &b in chunk {
+            if b.is_ascii_digit() {
+                if self.run_len == 0 && !self.overlong {
+                    self.run_start = self.position;
+                }
+                if self.run_len < DUF_LENGTH && !self.overlong {
+                    self.run[self.run_len] = b;
+                    self.run_len += 1;
+                } else {
+                    self.overlong = true;
+                    self.run_len = 0;
+                }
+            } else {
+                found.extend(self.end_run());
+            }
+            self.position += 1;
+        }
+        found
+    }
+
+    /// Ends the stream, returning the number at its very end, if any.
+    pub fn finish(mut self) -> Option<Match> {
+        self.end_run()
+    }
+
+    fn end_run(&mut self) -> Option<Match> {
+        let run = &self.run[..self.run_len];
+        self.run_len = 0;
+        self.overlong = false;
+        let s = std::str::from_utf8(run).ok()?;
+        match NorwegianTin::parse(s) {
+            Ok(tin) => Some(Match {
+                offset: self.run_start,
+                tin,
+            }),
+            Err(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+
+    const INPUT: &[u8] =
+        b"fnr=16057902284, org 974760673; konto 1234567890123456, bad 16057902285 \
+        d 70887100797";
+
+    fn scan_in_chunks(size: usize) -> Vec<Match> {
+        let mut scanner = Scanner::new();
+        let mut found = Vec::new();
+        for chunk in INPUT.chunks(size) {
+            found.extend(scanner.push(chunk));
+        }
+        found.extend(scanner.finish());
+        found
+    }
+
+    #[test]
+    fn test_scanner() {
+        let found = scan_in_chunks(INPUT.len());
+        let offsets: Vec<u64> = found.iter().map(|m| m.offset).collect();
+        assert_eq!(offsets, vec![4, 21, 74]);
+        assert_eq!(found[0].tin, NorwegianTin::parse("16057902284").unwrap());
+        assert_eq!(found[1].tin, NorwegianTin::parse("974760673").unwrap());
+        assert_eq!(found[2].tin, NorwegianTin::parse("70887100797").unwrap());
+
+        // Every chunk size splits some number across chunks
+        for size in 1..INPUT.len() {
+            assert_eq!(scan_in_chunks(size), found);
+        }
+    }
+
+    #[test]
+    fn test_scanner_run_boundaries() {
+        let mut scanner = Scanner::new();
+        assert_eq!(scanner.push(b"016057902284 1605790228"), vec![]);
+        assert_eq!(scanner.push(b"4"), vec![]);
+        let last = scanner.finish().unwrap();
+        assert_eq!(last.offset, 13);
+        assert!(Scanner::new().finish().is_none());
+    }
+}
@@ -0,0 +1,128 @@
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, SerializeMap, SerializeStruct, Serializer};
+
+use crate::{NorwegianTin, NorwegianTinError, TinKind};
+
+// Serialized as the full digit string, and validated again when deserialized
+impl Serialize for NorwegianTin {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let s: String = (*self).into();
+        serializer.serialize_str(&s)
+    }
+}
+
+impl<'de> Deserialize<'de> for NorwegianTin {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(NorwegianTinVisitor)
+    }
+}
+
+struct NorwegianTinVisitor;
+
+impl<'de> Visitor<'de> for NorwegianTinVisitor {
+    type Value = NorwegianTin;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a Norwegian TIN as a string of digits")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<NorwegianTin, E> {
+        NorwegianTin::parse(v).map_err(E::custom)
+    }
+}
+
+// Serialized as `{"code": ..., "details": {...}}`, with the codes of
+// `http::error_code` and the fields of the variant as details. Unlike
+// `Display`, the details can hold digits of the number.
+impl Serialize for NorwegianTinError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut error = serializer.serialize_struct("NorwegianTinError", 2)?;
+        error.serialize_field("code", crate::http::error_code(self))?;
+        error.serialize_field("details", &ErrorDetails(self))?;
+        error.end()
+    }
+}
+
+struct ErrorDetails<'a>(&'a NorwegianTinError);
+
+impl Serialize for ErrorDetails<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut details = serializer.serialize_map(None)?;
+        match self.0 {
+            NorwegianTinError::InvalidLength { found } => {
+                details.serialize_entry("found", found)?
+            }
+            NorwegianTinError::NonNumericValue { position } => {
+                details.serialize_entry("position", position)?
+            }
+            NorwegianTinError::InvalidChecksum {
+                position,
+                expected,
+                found,
+            } => {
+                details.serialize_entry("position", position)?;
+                details.serialize_entry("expected", expected)?;
+                details.serialize_entry("found", found)?;
+            }
+            NorwegianTinError::InvalidDate { year, month, day } => {
+                details.serialize_entry("year", year)?;
+                details.serialize_entry("month", month)?;
+                details.serialize_entry("day", day)?;
+            }
+            NorwegianTinError::WrongIdentifierType | NorwegianTinError::InvalidPrefix => {}
+            NorwegianTinError::FutureBirthDate { birth_date } => {
+                details.serialize_entry("birth_date", &birth_date.to_string())?
+            }
+            NorwegianTinError::NotAccepted { found } => {
+                let kind = match found {
+                    TinKind::FNumber => "f_number",
+                    TinKind::DNumber => "d_number",
+                    TinKind::OrgNumber => "org_number",
+                    TinKind::DufNumber => "duf_number",
+                };
+                details.serialize_entry("found", kind)?
+            }
+        }
+        details.end()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let tin = NorwegianTin::parse("16057902284").unwrap();
+        let json = serde_json::to_string(&tin).unwrap();
+        assert_eq!(json, "\"16057902284\"");
+        assert_eq!(serde_json::from_str::<NorwegianTin>(&json).unwrap(), tin);
+
+        let err = serde_json::from_str::<NorwegianTin>("\"12345678901\"").unwrap_err();
+        assert!(err.to_string().starts_with("InvalidChecksum"));
+        assert!(serde_json::from_str::<NorwegianTin>("16057902284").is_err());
+    }
+
+    #[test]
+    fn test_serialize_error() {
+        let err = NorwegianTin::parse("16057902285").unwrap_err();
+        assert_eq!(
+            serde_json::to_value(&err).unwrap(),
+            serde_json::json!({
+                "code": "TIN_CHECKSUM",
+                "details": {"position": 10, "expected": 4, "found": 5}
+            })
+        );
+        let err = NorwegianTinError::NotAccepted {
+            found: TinKind::OrgNumber,
+        };
+        assert_eq!(
+            serde_json::to_string(&err).unwrap(),
+            r#"{"code":"TIN_NOT_ACCEPTED","details":{"found":"org_number"}}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&NorwegianTinError::InvalidPrefix).unwrap(),
+            r#"{"code":"TIN_PREFIX","details":{}}"#
+        );
+    }
+}
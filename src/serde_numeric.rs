@@ -0,0 +1,137 @@
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::{self, Serializer};
+
+use crate::{NorwegianTin, NorwegianTinError, NumberWidth};
+
+/// For `#[serde(with = "serde_numeric::person")]` on F- and D-numbers stored
+/// as integers. Integers drop leading zeros, so a number born on the 1st to
+/// the 9th such as 06071732280 is written as `6071732280`, and is padded back
+/// to 11 digits when read. Prefer the default string form where possible.
+pub mod person {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(tin: &NorwegianTin, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_as(tin, NumberWidth::Person, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<NorwegianTin, D::Error> {
+        deserializer.deserialize_u64(NumberVisitor(NumberWidth::Person))
+    }
+}
+
+/// For `#[serde(with = "serde_numeric::org")]` on organisation numbers
+/// stored as integers.
+pub mod org {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(tin: &NorwegianTin, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_as(tin, NumberWidth::Org, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<NorwegianTin, D::Error> {
+        deserializer.deserialize_u64(NumberVisitor(NumberWidth::Org))
+    }
+}
+
+/// For `#[serde(with = "serde_numeric::duf")]` on DUF numbers stored as
+/// integers.
+pub mod duf {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(tin: &NorwegianTin, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_as(tin, NumberWidth::Duf, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<NorwegianTin, D::Error> {
+        deserializer.deserialize_u64(NumberVisitor(NumberWidth::Duf))
+    }
+}
+
+// Each kind has its own module since an integer can not tell the width it
+// was padded from. Writing a number of another kind fails, so what is written
+// always reads back.
+fn serialize_as<S: Serializer>(
+    tin: &NorwegianTin,
+    width: NumberWidth,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let fits = matches!(
+        (tin, width),
+        (NorwegianTin::FNumber(_), NumberWidth::Person)
+            | (NorwegianTin::DNumber(_), NumberWidth::Person)
+            | (NorwegianTin::OrgNumber(_), NumberWidth::Org)
+            | (NorwegianTin::DufNumber(_), NumberWidth::Duf)
+    );
+    if !fits {
+        return Err(ser::Error::custom(NorwegianTinError::WrongIdentifierType));
+    }
+    let n = tin.value().iter().fold(0u64, |n, &d| n * 10 + d as u64);
+    serializer.serialize_u64(n)
+}
+
+struct NumberVisitor(NumberWidth);
+
+impl<'de> Visitor<'de> for NumberVisitor {
+    type Value = NorwegianTin;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a Norwegian TIN as a non-negative integer")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<NorwegianTin, E> {
+        NorwegianTin::from_number(v, self.0).map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<NorwegianTin, E> {
+        if v < 0 {
+            return Err(E::invalid_value(de::Unexpected::Signed(v), &self));
+        }
+        self.visit_u64(v as u64)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "person")]
+        person: NorwegianTin,
+        #[serde(with = "org")]
+        org: NorwegianTin,
+    }
+
+    #[test]
+    fn test_serde_numeric() {
+        let record = Record {
+            person: NorwegianTin::parse("06071732280").unwrap(),
+            org: NorwegianTin::parse("974760673").unwrap(),
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(json, r#"{"person":6071732280,"org":974760673}"#);
+        assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+
+        assert!(
+            serde_json::from_str::<Record>(r#"{"person":6071732281,"org":974760673}"#).is_err()
+        );
+        assert!(serde_json::from_str::<Record>(r#"{"person":-1,"org":974760673}"#).is_err());
+        assert!(
+            serde_json::from_str::<Record>(r#"{"person":"06071732280","org":974760673}"#).is_err()
+        );
+
+        // An org number does not fit a person field
+        let swapped = Record {
+            person: record.org,
+            org: record.person,
+        };
+        assert!(serde_json::to_string(&swapped).is_err());
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+
+use crate::{DufNumber, NorwegianTin, OrgNumber, DUF_LENGTH, ORG_LENGTH, TIN_LENGTH};
+
+const TAG_ORG: u64 = 0;
+const TAG_PERSON: u64 = 1;
+const TAG_DUF: u64 = 2;
+
+/// A set of numbers stored as a sorted vector of 8 byte values, for large
+/// block lists and deduplication. Lookups are binary searches.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TinSet {
+    packed: Vec<u64>,
+}
+
+impl TinSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.packed.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packed.is_empty()
+    }
+
+    pub fn contains(&self, tin: &NorwegianTin) -> bool {
+        self.packed.binary_search(&pack(tin)).is_ok()
+    }
+
+    /// Adds `tin`, returning whether it was new. Inserting moves the larger
+    /// entries, so build large sets with `collect` or `extend` instead.
+    pub fn insert(&mut self, tin: NorwegianTin) -> bool {
+        let value = pack(&tin);
+        match self.packed.binary_search(&value) {
+            Ok(_) => false,
+            Err(i) => {
+                self.packed.insert(i, value);
+                true
+            }
+        }
+    }
+
+    /// Iterates in ascending numeric order of the digits.
+    pub fn iter(&self) -> impl Iterator<Item = NorwegianTin> + '_ {
+        self.packed.iter().map(|&value| unpack(value))
+    }
+
+    /// The numbers in either set, merged in linear time.
+    pub fn union(&self, other: &TinSet) -> TinSet {
+        let mut packed = Vec::with_capacity(self.len() + other.len());
+        let (mut a, mut b) = (
+            self.packed.iter().peekable(),
+            other.packed.iter().peekable(),
+        );
+        loop {
+            let next = match (a.peek(), b.peek()) {
+                (Some(&&x), Some(&&y)) if x < y => a.next(),
+                (Some(&&x), Some(&&y)) if x > y => b.next(),
+                (Some(_), Some(_)) => {
+                    b.next();
+                    a.next()
+                }
+                (Some(_), None) => a.next(),
+                (None, Some(_)) => b.next(),
+                (None, None) => break,
+            };
+            packed.extend(next);
+        }
+        TinSet { packed }
+    }
+
+    /// Heap memory used by the entries.
+    pub fn memory_bytes(&self) -> usize {
+        self.packed.capacity() * std::mem::size_of::<u64>()
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.packed.shrink_to_fit();
+    }
+}
+
+impl Extend<NorwegianTin> for TinSet {
+    fn extend<I: IntoIterator<Item = NorwegianTin>>(&mut self, iter: I) {
+        self.packed.extend(iter.into_iter().map(|tin| pack(&tin)));
+        self.packed.sort_unstable();
+        self.packed.dedup();
+    }
+}
+
+impl FromIterator<NorwegianTin> for TinSet {
+    fn from_iter<I: IntoIterator<Item = NorwegianTin>>(iter: I) -> Self {
+        let mut set = TinSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+/// A handle of a number interned in a `TinInterner`, valid for that
+/// interner only.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct TinHandle(u32);
+
+impl TinHandle {
+    /// Handles count up from 0 in the order the numbers were first interned,
+    /// so they can index a `Vec` of per-number data.
+    pub fn index(self) -> u32 {
+        self.0
+    }
+}
+
+/// Maps each distinct number to a `TinHandle`, for working on 4 byte handles
+/// in jobs that see the same numbers many times. Both directions are a hash
+/// lookup or an index into a vector of 8 byte values.
+#[derive(Debug, Clone, Default)]
+pub struct TinInterner {
+    handles: HashMap<u64, u32>,
+    packed: Vec<u64>,
+}
+
+impl TinInterner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        TinInterner {
+            handles: HashMap::with_capacity(capacity),
+            packed: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.packed.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packed.is_empty()
+    }
+
+    /// The handle of `tin`, interning it if it is new.
+    ///
+    /// Panics when more than `u32::MAX` distinct numbers are interned.
+    pub fn intern(&mut self, tin: &NorwegianTin) -> TinHandle {
+        let value = pack(tin);
+        let packed = &mut self.packed;
+        let handle = *self.handles.entry(value).or_insert_with(|| {
+            let handle = u32::try_from(packed.len()).expect("more than u32::MAX numbers");
+            packed.push(value);
+            handle
+        });
+        TinHandle(handle)
+    }
+
+    /// The handle of `tin` if it has been interned.
+    pub fn get(&self, tin: &NorwegianTin) -> Option<TinHandle> {
+        self.handles
+            .get(&pack(tin))
+            .map(|&handle| TinHandle(handle))
+    }
+
+    /// The number behind `handle`, `None` for handles of another interner.
+    pub fn resolve(&self, handle: TinHandle) -> Option<NorwegianTin> {
+        self.packed
+            .get(handle.0 as usize)
+            .map(|&value| unpack(value))
+    }
+
+    /// Iterates over the numbers in the order of their handles.
+    pub fn iter(&self) -> impl Iterator<Item = (TinHandle, NorwegianTin)> + '_ {
+        self.packed
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| (TinHandle(i as u32), unpack(value)))
+    }
+}
+
+/// The digits as an integer, times four, plus a tag telling the length. Up to
+/// 12 digits fit with room to spare.
+pub(crate) fn pack(tin: &NorwegianTin) -> u64 {
+    let tag = match tin {
+        NorwegianTin::OrgNumber(_) => TAG_ORG,
+        NorwegianTin::FNumber(_) | NorwegianTin::DNumber(_) => TAG_PERSON,
+        NorwegianTin::DufNumber(_) => TAG_DUF,
+    };
+    let number = tin.value().iter().fold(0u64, |acc, &d| acc * 10 + d as u64);
+    number << 2 | tag
+}
+
+fn unpack(value: u64) -> NorwegianTin {
+    let mut number = value >> 2;
+    let mut digits = [0u8; DUF_LENGTH];
+    let len = match value & 3 {
+        TAG_ORG => ORG_LENGTH,
+        TAG_PERSON => TIN_LENGTH,
+        _ => DUF_LENGTH,
+    };
+    for d in digits[..len].iter_mut().rev() {
+        *d = (number % 10) as u8;
+        number /= 10;
+    }
+    match len {
+        ORG_LENGTH => {
+            let mut org = [0u8; ORG_LENGTH];
+            org.copy_from_slice(&digits[..ORG_LENGTH]);
+            NorwegianTin::OrgNumber(OrgNumber::new_unchecked(org))
+        }
+        TIN_LENGTH => {
+            let mut person = [0u8; TIN_LENGTH];
+            person.copy_from_slice(&digits[..TIN_LENGTH]);
+            NorwegianTin::new_unchecked(person)
+        }
+        _ => NorwegianTin::DufNumber(DufNumber { value: digits }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use crate::TinKind;
+
+    #[test]
+    fn test_tin_set() {
+        let values = vec![
+            "16057902284",
+            "974760673",
+            "70887100797",
+            "201234567800",
+            "16057902284",
+            "01010150074",
+        ];
+        let set: TinSet = values
+            .iter()
+            .map(|v| NorwegianTin::parse(v).unwrap())
+            .collect();
+        assert_eq!(set.len(), 5);
+        for value in &values {
+            assert!(set.contains(&NorwegianTin::parse(value).unwrap()));
+        }
+        assert!(!set.contains(&NorwegianTin::parse("905661833").unwrap()));
+
+        let iterated: Vec<String> = set.iter().map(String::from).collect();
+        assert_eq!(
+            iterated,
+            vec![
+                "974760673",
+                "01010150074",
+                "16057902284",
+                "70887100797",
+                "201234567800"
+            ]
+        );
+        let kinds: Vec<TinKind> = set.iter().map(|tin| tin.tin_kind()).collect();
+        assert_eq!(kinds[3], TinKind::DNumber);
+    }
+
+    #[test]
+    fn test_insert_and_union() {
+        let mut a = TinSet::new();
+        assert!(a.insert(NorwegianTin::parse("16057902284").unwrap()));
+        assert!(!a.insert(NorwegianTin::parse("16057902284").unwrap()));
+        assert!(a.insert(NorwegianTin::parse("905661833").unwrap()));
+
+        let b: TinSet = vec!["905661833", "974760673"]
+            .into_iter()
+            .map(|v| NorwegianTin::parse(v).unwrap())
+            .collect();
+        let union = a.union(&b);
+        assert_eq!(union.len(), 3);
+        assert_eq!(union, b.union(&a));
+        assert!(union.iter().all(|tin| a.contains(&tin) || b.contains(&tin)));
+        assert_eq!(TinSet::new().union(&TinSet::new()), TinSet::new());
+        assert_eq!(union.memory_bytes() % 8, 0);
+    }
+
+    #[test]
+    fn test_interner() {
+        let mut interner = TinInterner::new();
+        let person = NorwegianTin::parse("06071732280").unwrap();
+        let org = NorwegianTin::parse("974760673").unwrap();
+        let duf = NorwegianTin::parse("201234567800").unwrap();
+        assert_eq!(interner.intern(&person).index(), 0);
+        assert_eq!(interner.intern(&org).index(), 1);
+        assert_eq!(interner.intern(&person).index(), 0);
+        let handle = interner.intern(&duf);
+        assert_eq!(interner.len(), 3);
+
+        assert_eq!(interner.get(&org), Some(TinHandle(1)));
+        assert_eq!(
+            interner.get(&NorwegianTin::parse("905661833").unwrap()),
+            None
+        );
+        assert_eq!(interner.resolve(handle), Some(duf));
+        assert_eq!(interner.resolve(TinHandle(0)), Some(person));
+        assert_eq!(interner.resolve(TinHandle(3)), None);
+        let tins: Vec<NorwegianTin> = interner.iter().map(|(_, tin)| tin).collect();
+        assert_eq!(tins, vec![person, org, duf]);
+    }
+}
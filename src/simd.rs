@@ -0,0 +1,142 @@
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::TIN_LENGTH;
+
+/// Verifies digit-ness and both checksums of each record with SSSE3, writing
+/// the verdict for `records[i]` to `out[i]`. Returns `false` without touching
+/// `out` when the CPU lacks SSSE3, so the caller can fall back to scalar code.
+pub(crate) fn verify_checksums(records: &[[u8; TIN_LENGTH]], out: &mut [bool]) -> bool {
+    if !is_x86_feature_detected!("ssse3") {
+        return false;
+    }
+    // Safety: SSSE3 support was checked above
+    unsafe { verify_checksums_ssse3(records, out) };
+    true
+}
+
+// The check digits get weight 1, so each sum only has to be reduced modulo 11.
+// A record is loaded either into lanes 0..11 of a register, or into lanes 5..16
+// by loading from five bytes before its start, so two neighbouring records fit
+// in one 32 byte window.
+#[target_feature(enable = "ssse3")]
+unsafe fn first_weights_low() -> __m128i {
+    _mm_setr_epi8(3, 7, 6, 1, 8, 9, 4, 5, 2, 1, 0, 0, 0, 0, 0, 0)
+}
+
+#[target_feature(enable = "ssse3")]
+unsafe fn second_weights_low() -> __m128i {
+    _mm_setr_epi8(5, 4, 3, 2, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0)
+}
+
+#[target_feature(enable = "ssse3")]
+unsafe fn first_weights_high() -> __m128i {
+    _mm_setr_epi8(0, 0, 0, 0, 0, 3, 7, 6, 1, 8, 9, 4, 5, 2, 1, 0)
+}
+
+#[target_feature(enable = "ssse3")]
+unsafe fn second_weights_high() -> __m128i {
+    _mm_setr_epi8(0, 0, 0, 0, 0, 5, 4, 3, 2, 7, 6, 5, 4, 3, 2, 1)
+}
+
+const LOW_LANES: i32 = 0x07FF;
+const HIGH_LANES: i32 = 0xFFE0;
+
+/// Verifies four records per iteration: their eight weighted sums are packed
+/// into a single register and reduced modulo 11 together. The last one to
+/// three records are verified one at a time.
+#[target_feature(enable = "ssse3")]
+unsafe fn verify_checksums_ssse3(records: &[[u8; TIN_LENGTH]], out: &mut [bool]) {
+    let quads = records.chunks_exact(4);
+    let rest = quads.remainder();
+    let mut verdicts = out.chunks_exact_mut(4);
+    for (quad, verdict) in quads.zip(&mut verdicts) {
+        // The four records are 44 contiguous bytes, and every load stays within them
+        let base = quad.as_ptr() as *const u8;
+        let a = Digits::load(base, LOW_LANES);
+        let b = Digits::load(base.add(6), HIGH_LANES);
+        let c = Digits::load(base.add(22), LOW_LANES);
+        let d = Digits::load(base.add(28), HIGH_LANES);
+
+        let (low_first, low_second) = (first_weights_low(), second_weights_low());
+        let (high_first, high_second) = (first_weights_high(), second_weights_high());
+        // [A1, A2, B1, B2] and [C1, C2, D1, D2]
+        let ab = _mm_hadd_epi32(
+            a.sums(low_first, low_second),
+            b.sums(high_first, high_second),
+        );
+        let cd = _mm_hadd_epi32(
+            c.sums(low_first, low_second),
+            d.sums(high_first, high_second),
+        );
+        // The sums are at most 414, so they fit 16 bit lanes, where
+        // x * 5958 >> 16 is x / 11 for every possible sum
+        let sums = _mm_packs_epi32(ab, cd);
+        let quotients = _mm_mulhi_epu16(sums, _mm_set1_epi16(5958));
+        let remainders = _mm_sub_epi16(sums, _mm_mullo_epi16(quotients, _mm_set1_epi16(11)));
+        // From 2032 the first check digit may leave a remainder up to 3
+        let limits = _mm_setr_epi16(3, 0, 3, 0, 3, 0, 3, 0);
+        let failed = _mm_movemask_epi8(_mm_cmpgt_epi16(remainders, limits));
+
+        for (i, (digits, verdict)) in [a, b, c, d].iter().zip(verdict).enumerate() {
+            *verdict = digits.all_digits && (failed >> (i * 4)) & 0xF == 0;
+        }
+    }
+    for (record, verdict) in rest.iter().zip(verdicts.into_remainder()) {
+        *verdict = verify_one(record);
+    }
+}
+
+/// The digit values of one record in a register, and whether all of its
+/// bytes were digits.
+#[derive(Clone, Copy)]
+struct Digits {
+    digits: __m128i,
+    all_digits: bool,
+}
+
+impl Digits {
+    /// Loads 16 bytes from `ptr`, of which the record occupies `lanes`.
+    #[target_feature(enable = "ssse3")]
+    unsafe fn load(ptr: *const u8, lanes: i32) -> Digits {
+        let digits = _mm_sub_epi8(
+            _mm_loadu_si128(ptr as *const __m128i),
+            _mm_set1_epi8(b'0' as i8),
+        );
+        // Bytes below '0' wrap around, so an unsigned min with 9 catches both ends
+        let is_digit = _mm_cmpeq_epi8(_mm_min_epu8(digits, _mm_set1_epi8(9)), digits);
+        Digits {
+            digits,
+            all_digits: _mm_movemask_epi8(is_digit) & lanes == lanes,
+        }
+    }
+
+    /// The partial sums [first, first, second, second] of the record. Bytes
+    /// of neighbouring records have weight 0.
+    #[target_feature(enable = "ssse3")]
+    unsafe fn sums(self, first_weights: __m128i, second_weights: __m128i) -> __m128i {
+        let ones = _mm_set1_epi16(1);
+        let first = _mm_madd_epi16(_mm_maddubs_epi16(self.digits, first_weights), ones);
+        let second = _mm_madd_epi16(_mm_maddubs_epi16(self.digits, second_weights), ones);
+        _mm_hadd_epi32(first, second)
+    }
+}
+
+#[target_feature(enable = "ssse3")]
+unsafe fn verify_one(record: &[u8; TIN_LENGTH]) -> bool {
+    // Pad to a full register with '0', which contributes nothing to either sum
+    let mut padded = [b'0'; 16];
+    padded[..TIN_LENGTH].copy_from_slice(record);
+    let digits = Digits::load(padded.as_ptr(), 0xFFFF);
+    if !digits.all_digits {
+        return false;
+    }
+    // [first, second, first, second]
+    let totals = digits.sums(first_weights_low(), second_weights_low());
+    let totals = _mm_hadd_epi32(totals, totals);
+    let first = _mm_cvtsi128_si32(totals) as u32;
+    let second = _mm_cvtsi128_si32(_mm_srli_si128::<4>(totals)) as u32;
+    first % 11 <= 3 && second % 11 == 0
+}
@@ -0,0 +1,92 @@
+use ::sqlx::encode::IsNull;
+use ::sqlx::error::BoxDynError;
+use ::sqlx::{Database, Decode, Encode, Type};
+
+use crate::NorwegianTin;
+
+/// `NorwegianTin` is stored as its digit string in a TEXT, VARCHAR or CHAR
+/// column, so it is compatible with every column type that accepts `str`.
+impl<DB: Database> Type<DB> for NorwegianTin
+where
+    str: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <str as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <str as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for NorwegianTin
+where
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let value: String = (*self).into();
+        <String as Encode<'q, DB>>::encode(value, buf)
+    }
+}
+
+/// Decodes with `NorwegianTin::parse`, so an invalid value is a decode error.
+impl<'r, DB: Database> Decode<'r, DB> for NorwegianTin
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let value = <&'r str as Decode<'r, DB>>::decode(value)?;
+        Ok(NorwegianTin::parse(value)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::sqlx::mysql::MySql;
+    use ::sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, Postgres};
+
+    #[test]
+    fn test_type_info() {
+        assert_eq!(
+            <NorwegianTin as Type<Postgres>>::type_info(),
+            <str as Type<Postgres>>::type_info()
+        );
+        assert_eq!(
+            <NorwegianTin as Type<MySql>>::type_info(),
+            <str as Type<MySql>>::type_info()
+        );
+    }
+
+    #[test]
+    fn test_compatible() {
+        // VARCHAR and CHAR columns are accepted, while integer columns would
+        // have dropped the leading zeros
+        for (name, compatible) in [
+            ("varchar", true),
+            ("bpchar", true),
+            ("int8", false),
+            ("numeric", false),
+        ] {
+            let ty = PgTypeInfo::with_name(name);
+            assert_eq!(
+                <NorwegianTin as Type<Postgres>>::compatible(&ty),
+                compatible,
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_encode() {
+        let tin = NorwegianTin::parse("06071732280").unwrap();
+        let mut buf = PgArgumentBuffer::default();
+        let is_null = <NorwegianTin as Encode<Postgres>>::encode_by_ref(&tin, &mut buf).unwrap();
+        assert!(matches!(is_null, IsNull::No));
+        assert_eq!(&buf[..], b"06071732280");
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use crate::{NorwegianTin, DUF_LENGTH, ORG_LENGTH, TIN_LENGTH};
+
+/// A valid number near the input, and the number of edits to reach it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Suggestion {
+    pub tin: NorwegianTin,
+    pub distance: usize,
+}
+
+/// Valid numbers within `max_edits` digit substitutions or adjacent
+/// transpositions of `s`, closest first and then in numeric order. A valid
+/// input is returned itself with distance 0.
+///
+/// Each edit multiplies the search by about a hundred, so keep `max_edits` at
+/// one or two. Inputs that do not have the length of a number give no
+/// suggestions, since neither edit changes the length.
+pub fn closest_valid(s: &str, max_edits: usize) -> Vec<Suggestion> {
+    if !matches!(s.len(), ORG_LENGTH | TIN_LENGTH | DUF_LENGTH) {
+        return Vec::new();
+    }
+    let start = s.as_bytes().to_vec();
+    let mut seen: HashMap<Vec<u8>, usize> = HashMap::new();
+    seen.insert(start.clone(), 0);
+    let mut frontier = vec![start];
+    for distance in 1..=max_edits {
+        let mut next = Vec::new();
+        for candidate in &frontier {
+            for neighbour in neighbours(candidate) {
+                if !seen.contains_key(&neighbour) {
+                    seen.insert(neighbour.clone(), distance);
+                    next.push(neighbour);
+                }
+            }
+        }
+        frontier = next;
+    }
+
+    let mut suggestions: Vec<Suggestion> = seen
+        .into_iter()
+        .filter_map(|(bytes, distance)| {
+            let tin = NorwegianTin::parse(std::str::from_utf8(&bytes).ok()?).ok()?;
+            Some(Suggestion { tin, distance })
+        })
+        .collect();
+    suggestions.sort_by(|a, b| {
+        a.distance
+            .cmp(&b.distance)
+            .then_with(|| a.tin.value().cmp(b.tin.value()))
+    });
+    suggestions
+}
+
+/// Every input one edit away: each position replaced by another digit, and
+/// each pair of different adjacent characters swapped.
+fn neighbours(bytes: &[u8]) -> Vec<Vec<u8>> {
+    let mut out = Vec::with_capacity(bytes.len() * 10);
+    for i in 0..bytes.len() {
+        for digit in b'0'..=b'9' {
+            if bytes[i] != digit {
+                let mut candidate = bytes.to_vec();
+                candidate[i] = digit;
+                out.push(candidate);
+            }
+        }
+        if i + 1 < bytes.len() && bytes[i] != bytes[i + 1] {
+            let mut candidate = bytes.to_vec();
+            candidate.swap(i, i + 1);
+            out.push(candidate);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+
+    #[test]
+    fn test_closest_valid() {
+        let expected = NorwegianTin::parse("16057902284").unwrap();
+        let tests = vec![
+            // Wrong check digit
+            "16057902285",
+            // Transposed day and month digits
+            "16075902284",
+            // Misread character
+            "1605790228a",
+        ];
+        for input in tests {
+            let suggestions = closest_valid(input, 1);
+            assert!(suggestions.contains(&Suggestion {
+                tin: expected,
+                distance: 1
+            }));
+            assert!(suggestions.iter().all(|s| s.distance == 1));
+        }
+    }
+
+    #[test]
+    fn test_closest_valid_ranking() {
+        let suggestions = closest_valid("974760673", 2);
+        assert_eq!(
+            suggestions[0],
+            Suggestion {
+                tin: NorwegianTin::parse("974760673").unwrap(),
+                distance: 0
+            }
+        );
+        assert!(suggestions.len() > 1);
+        assert!(suggestions
+            .windows(2)
+            .all(|pair| pair[0].distance <= pair[1].distance));
+        assert!(suggestions.iter().all(|s| s.distance <= 2));
+
+        assert!(closest_valid("1605790228", 2).is_empty());
+        assert_eq!(closest_valid("16057902285", 0), vec![]);
+    }
+}
@@ -0,0 +1,272 @@
+use std::str::FromStr;
+
+use crate::{Date, NorwegianTinError, Tin};
+
+const PERSONNUMMER_LENGTH: usize = 10;
+const COORDINATION_DAY_OFFSET: u8 = 60;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum SwedishNumberKind {
+    Personnummer,
+    /// Coordination number, with 60 added to the day of birth.
+    Samordningsnummer,
+}
+
+/// A Swedish personnummer or samordningsnummer.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct SwedishPersonalNumber {
+    kind: SwedishNumberKind,
+    /// The first two digits of the birth year, when given in the input.
+    century: Option<u8>,
+    value: [u8; PERSONNUMMER_LENGTH],
+}
+
+impl SwedishPersonalNumber {
+    /// Parses "YYMMDD-NNNC", "YYMMDD+NNNC", "YYYYMMDD-NNNC" or the same
+    /// without separator.
+    pub fn parse(s: &str) -> Result<SwedishPersonalNumber, NorwegianTinError> {
+        let bytes = s.as_bytes();
+        let (date, serial) = match bytes.len() {
+            10 | 12 => bytes.split_at(bytes.len() - 4),
+            11 | 13 => {
+                let (date, rest) = bytes.split_at(bytes.len() - 5);
+                if rest[0] != b'-' && rest[0] != b'+' {
+                    return Err(NorwegianTinError::NonNumericValue {
+                        position: date.len(),
+                    });
+                }
+                (date, &rest[1..])
+            }
+            found => return Err(NorwegianTinError::InvalidLength { found }),
+        };
+        if let Some(i) = date
+            .iter()
+            .chain(serial.iter())
+            .position(|b| !b.is_ascii_digit())
+        {
+            let position = if i < date.len() {
+                i
+            } else {
+                i + bytes.len() - date.len() - serial.len()
+            };
+            return Err(NorwegianTinError::NonNumericValue { position });
+        }
+        let century = match date.len() {
+            8 => Some((date[0] - b'0') * 10 + (date[1] - b'0')),
+            _ => None,
+        };
+
+        let mut digits = [0u8; PERSONNUMMER_LENGTH];
+        for (i, &b) in date[date.len() - 6..]
+            .iter()
+            .chain(serial.iter())
+            .enumerate()
+        {
+            digits[i] = b - b'0';
+        }
+        Self::from_digits(digits, century)
+            .map_err(|err| err.shifted(bytes.len() - PERSONNUMMER_LENGTH))
+    }
+
+    fn from_digits(
+        digits: [u8; PERSONNUMMER_LENGTH],
+        century: Option<u8>,
+    ) -> Result<SwedishPersonalNumber, NorwegianTinError> {
+        let expected = luhn_check_digit(&digits[0..9]);
+        if expected != digits[9] {
+            return Err(NorwegianTinError::InvalidChecksum {
+                position: 9,
+                expected: Some(expected),
+                found: digits[9],
+            });
+        }
+
+        let month = digits[2] * 10 + digits[3];
+        let day = digits[4] * 10 + digits[5];
+        let (kind, day) = match day {
+            61..=91 => (
+                SwedishNumberKind::Samordningsnummer,
+                day - COORDINATION_DAY_OFFSET,
+            ),
+            _ => (SwedishNumberKind::Personnummer, day),
+        };
+        // Without a century, 2000 + YY accepts 29 February exactly when YY % 4 == 0
+        let year = century.unwrap_or(20) as u16 * 100 + digits[0] as u16 * 10 + digits[1] as u16;
+        Date::new(year, month, day)?;
+        Ok(SwedishPersonalNumber {
+            kind,
+            century,
+            value: digits,
+        })
+    }
+
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    pub fn kind(&self) -> SwedishNumberKind {
+        self.kind
+    }
+
+    /// The full birth year, when the input used the 12 digit form.
+    pub fn birth_year(&self) -> Option<u16> {
+        self.century
+            .map(|c| c as u16 * 100 + self.value[0] as u16 * 10 + self.value[1] as u16)
+    }
+
+    /// The birth date, when the input used the 12 digit form.
+    pub fn birth_date(&self) -> Option<Date> {
+        let month = self.value[2] * 10 + self.value[3];
+        let day = match self.value[4] * 10 + self.value[5] {
+            day if self.kind == SwedishNumberKind::Samordningsnummer => {
+                day - COORDINATION_DAY_OFFSET
+            }
+            day => day,
+        };
+        Date::new(self.birth_year()?, month, day).ok()
+    }
+}
+
+impl Tin for SwedishPersonalNumber {
+    type Kind = SwedishNumberKind;
+
+    fn kind(&self) -> SwedishNumberKind {
+        self.kind
+    }
+
+    fn canonical_string(&self) -> String {
+        (*self).into()
+    }
+
+    fn birth_date(&self) -> Option<Date> {
+        SwedishPersonalNumber::birth_date(self)
+    }
+}
+
+/// Returns whether `s` is a valid personnummer or samordningsnummer.
+pub fn is_valid(s: &str) -> bool {
+    SwedishPersonalNumber::parse(s).is_ok()
+}
+
+fn luhn_check_digit(digits: &[u8]) -> u8 {
+    let sum: u32 = digits
+        .iter()
+        .enumerate()
+        .map(|(i, &d)| match (d as u32) * (2 - (i as u32 % 2)) {
+            p if p > 9 => p - 9,
+            p => p,
+        })
+        .sum();
+    ((10 - sum % 10) % 10) as u8
+}
+
+impl FromStr for SwedishPersonalNumber {
+    type Err = NorwegianTinError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl From<SwedishPersonalNumber> for String {
+    fn from(number: SwedishPersonalNumber) -> String {
+        number.value.iter().map(|&d| (d + b'0') as char).collect()
+    }
+}
+
+impl AsRef<[u8]> for SwedishPersonalNumber {
+    fn as_ref(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+impl std::fmt::Display for SwedishPersonalNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s: String = (*self).into();
+        let kind = match self.kind {
+            SwedishNumberKind::Samordningsnummer => " (Samordningsnummer) ",
+            SwedishNumberKind::Personnummer => "",
+        };
+        // Masking the last 4 digits for privacy
+        write!(f, "{}{}-****", kind, &s[0..6])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use crate::ErrorKind;
+
+    #[test]
+    fn test_valid_personnummer() {
+        let numbers = vec![
+            "811218-9876",
+            "8112189876",
+            "19811218-9876",
+            "198112189876",
+            "640823-3234",
+            "121212+1212",
+            "200002291235",
+        ];
+        for number in numbers {
+            let parsed = SwedishPersonalNumber::parse(number).unwrap();
+            assert_eq!(parsed.kind(), SwedishNumberKind::Personnummer);
+            assert!(is_valid(number));
+        }
+    }
+
+    #[test]
+    fn test_samordningsnummer() {
+        let parsed = SwedishPersonalNumber::parse("701063-2342").unwrap();
+        assert_eq!(parsed.kind(), SwedishNumberKind::Samordningsnummer);
+        assert_eq!(format!("{}", parsed), " (Samordningsnummer) 701063-****");
+    }
+
+    #[test]
+    fn test_invalid_personnummer() {
+        let checksum = vec!["811218-9875", "8112189877"];
+        for number in checksum {
+            assert_eq!(
+                SwedishPersonalNumber::parse(number).unwrap_err().kind(),
+                ErrorKind::InvalidChecksum
+            );
+        }
+        assert_eq!(
+            SwedishPersonalNumber::parse("19811218-9875").unwrap_err(),
+            NorwegianTinError::InvalidChecksum {
+                position: 12,
+                expected: Some(6),
+                found: 5,
+            }
+        );
+        assert_eq!(
+            SwedishPersonalNumber::parse("190002291235")
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidDate
+        );
+        assert_eq!(
+            SwedishPersonalNumber::parse("811218*9876")
+                .unwrap_err()
+                .kind(),
+            ErrorKind::NonNumericValue
+        );
+        assert_eq!(
+            SwedishPersonalNumber::parse("81121898").unwrap_err().kind(),
+            ErrorKind::InvalidLength
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let parsed = SwedishPersonalNumber::parse("19811218-9876").unwrap();
+        assert_eq!(format!("{}", parsed), "811218-****");
+        assert_eq!(parsed.birth_year(), Some(1981));
+        assert_eq!(parsed.birth_date(), Some(Date::new(1981, 12, 18).unwrap()));
+        assert_eq!(
+            SwedishPersonalNumber::parse("811218-9876")
+                .unwrap()
+                .birth_year(),
+            None
+        );
+    }
+}
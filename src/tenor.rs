@@ -0,0 +1,126 @@
+use serde::Deserialize;
+
+use crate::{NorwegianTin, PersonKind, PersonNumber};
+
+const DEFAULT_BASE_URL: &str = "https://testdata.api.skatteetaten.no/api/testnorge/v2";
+
+#[derive(Debug)]
+pub enum TenorError {
+    Http(reqwest::Error),
+    UnexpectedStatus(u16),
+}
+
+impl std::fmt::Display for TenorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TenorError::Http(err) => write!(f, "Http({})", err),
+            TenorError::UnexpectedStatus(status) => write!(f, "UnexpectedStatus({})", status),
+        }
+    }
+}
+
+impl std::error::Error for TenorError {}
+
+impl From<reqwest::Error> for TenorError {
+    fn from(err: reqwest::Error) -> Self {
+        TenorError::Http(err)
+    }
+}
+
+#[derive(Deserialize)]
+struct SearchResult {
+    treff: u64,
+}
+
+/// Async client for the Tenor test data search API at Skatteetaten, telling
+/// whether a synthetic number is provisioned in the Folkeregisteret test
+/// population. Requests need a Maskinporten access token with the
+/// `skatteetaten:testnorge/testdata.read` scope.
+#[derive(Debug, Clone)]
+pub struct TenorClient {
+    http: reqwest::Client,
+    base_url: String,
+    token: String,
+}
+
+impl TenorClient {
+    pub fn new(token: &str) -> Self {
+        Self::with_base_url(DEFAULT_BASE_URL, token)
+    }
+
+    pub fn with_base_url(base_url: &str, token: &str) -> Self {
+        Self::with_client(reqwest::Client::new(), base_url, token)
+    }
+
+    pub fn with_client(http: reqwest::Client, base_url: &str, token: &str) -> Self {
+        TenorClient {
+            http,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token: token.to_string(),
+        }
+    }
+
+    /// Returns whether `person` is a test person in Tenor. Only synthetic
+    /// numbers exist there, so other numbers return `false` without being
+    /// sent to the API.
+    pub async fn exists(&self, person: &PersonNumber) -> Result<bool, TenorError> {
+        if person.kind != PersonKind::Synthetic {
+            return Ok(false);
+        }
+        let number: String = (*person).into();
+        let response = self
+            .http
+            .get(format!("{}/soek/freg", self.base_url))
+            .query(&[
+                ("kql", search_query(&number)),
+                ("antall", String::from("1")),
+            ])
+            .bearer_auth(&self.token)
+            .header(reqwest::header::ACCEPT, "application/json")
+            .send()
+            .await?;
+        match response.status().as_u16() {
+            200 => Ok(response.json::<SearchResult>().await?.treff > 0),
+            status => Err(TenorError::UnexpectedStatus(status)),
+        }
+    }
+
+    /// Like `exists`, for any number. Organisation and DUF numbers are never
+    /// test persons.
+    pub async fn exists_tin(&self, tin: &NorwegianTin) -> Result<bool, TenorError> {
+        match tin.as_person() {
+            Some(person) => self.exists(person).await,
+            None => Ok(false),
+        }
+    }
+}
+
+fn search_query(number: &str) -> String {
+    format!("identifikator:\"{}\"", number)
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+
+    #[test]
+    fn test_search_result() {
+        let body = r#"{ "treff": 1, "rader": 1, "offset": 0, "dokumentListe": [{}] }"#;
+        let result: SearchResult = serde_json::from_str(body).unwrap();
+        assert_eq!(result.treff, 1);
+        assert_eq!(search_query("70887100797"), "identifikator:\"70887100797\"");
+    }
+
+    #[test]
+    fn test_only_synthetic_numbers_are_looked_up() {
+        let client = TenorClient::with_base_url("http://127.0.0.1:9", "token");
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let tins = vec!["16057902284", "22517149261", "974760673"];
+        for tin in tins {
+            let tin = NorwegianTin::parse(tin).unwrap();
+            assert!(!runtime.block_on(client.exists_tin(&tin)).unwrap());
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use std::io;
+
+use ::tokio::io::{AsyncBufRead, AsyncBufReadExt};
+
+use crate::scan::{Match, Scanner};
+
+/// Finds the valid numbers in `reader` like `scan::Scanner`, reading it one
+/// buffer at a time so large files are scanned without blocking the runtime
+/// or being read into memory.
+pub async fn scan_async<R: AsyncBufRead + Unpin>(reader: &mut R) -> io::Result<Vec<Match>> {
+    let mut scanner = Scanner::new();
+    let mut found = Vec::new();
+    loop {
+        let chunk = reader.fill_buf().await?;
+        if chunk.is_empty() {
+            break;
+        }
+        let len = chunk.len();
+        found.extend(scanner.push(chunk));
+        reader.consume(len);
+    }
+    found.extend(scanner.finish());
+    Ok(found)
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use crate::NorwegianTin;
+    use ::tokio::io::BufReader;
+
+    #[::tokio::test]
+    async fn test_scan_async() {
+        let input: &[u8] = b"user 16057902284 logged in from org 974760673\nuser 70887100797";
+        // A small buffer splits the numbers across reads
+        let mut reader = BufReader::with_capacity(4, input);
+        let found = scan_async(&mut reader).await.unwrap();
+        let tins: Vec<NorwegianTin> = found.iter().map(|m| m.tin).collect();
+        assert_eq!(
+            tins,
+            vec![
+                NorwegianTin::parse("16057902284").unwrap(),
+                NorwegianTin::parse("974760673").unwrap(),
+                NorwegianTin::parse("70887100797").unwrap(),
+            ]
+        );
+        assert_eq!(found[2].offset, 51);
+    }
+}
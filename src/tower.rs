@@ -0,0 +1,322 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use http::header::{HeaderName, CONTENT_TYPE};
+use http::request::Parts;
+use http::{Request, Response, StatusCode};
+use http_body::Body;
+use http_body_util::{BodyExt, Either, Full};
+use serde_json::Value;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::{NorwegianTin, NorwegianTinError};
+
+/// A request field holding a number.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TinField {
+    Header(HeaderName),
+    /// A key of the top level JSON object, or a JSON pointer such as
+    /// "/customer/tin" for nested values.
+    Json(String),
+}
+
+/// Validates the configured fields of every request before it reaches the
+/// inner service, and rejects requests with an invalid number with 422
+/// Unprocessable Entity and a body like
+/// `{"error":"InvalidChecksum","code":"TIN_CHECKSUM","field":"x-customer-tin"}`.
+///
+/// Missing and null fields are let through, so required fields are still
+/// checked by the handler, while JSON fields holding anything but a string,
+/// and headers that are not visible ASCII, are rejected with a body like
+/// `{"error":"UnexpectedType","field":"/customer/tin"}`. With JSON fields configured the whole body is buffered, so
+/// limit its size in an outer layer; bodies that are not JSON are passed on
+/// unchecked for the handler to reject. The inner service receives the
+/// buffered body as `Either::Left(Full<Bytes>)`, or the original body as
+/// `Either::Right` when only headers are configured. axum routers accept
+/// both.
+#[derive(Debug, Default, Clone)]
+pub struct ValidateTinLayer {
+    fields: Arc<Vec<TinField>>,
+}
+
+impl ValidateTinLayer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn header(self, name: HeaderName) -> Self {
+        self.field(TinField::Header(name))
+    }
+
+    pub fn json_field(self, field: &str) -> Self {
+        self.field(TinField::Json(field.to_string()))
+    }
+
+    pub fn field(mut self, field: TinField) -> Self {
+        Arc::make_mut(&mut self.fields).push(field);
+        self
+    }
+}
+
+impl<S> Layer<S> for ValidateTinLayer {
+    type Service = ValidateTin<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ValidateTin {
+            inner,
+            fields: self.fields.clone(),
+        }
+    }
+}
+
+/// The service of `ValidateTinLayer`.
+#[derive(Debug, Clone)]
+pub struct ValidateTin<S> {
+    inner: S,
+    fields: Arc<Vec<TinField>>,
+}
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+impl<S, B, ResBody> Service<Request<B>> for ValidateTin<S>
+where
+    S: Service<Request<Either<Full<Bytes>, B>>, Response = Response<ResBody>>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send,
+    B: Body<Data = Bytes> + Send + 'static,
+    ResBody: From<String>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<Result<S::Response, S::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        // The clone may not be ready, so the ready service is moved into the
+        // future and the clone kept for the next call
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let fields = self.fields.clone();
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            if !fields
+                .iter()
+                .any(|field| matches!(field, TinField::Json(_)))
+            {
+                if let Some(response) = validate(&fields, &parts, &[]) {
+                    return Ok(response);
+                }
+                return inner
+                    .call(Request::from_parts(parts, Either::Right(body)))
+                    .await;
+            }
+            let body = match body.collect().await {
+                Ok(collected) => collected.to_bytes(),
+                Err(_) => {
+                    return Ok(reject(
+                        StatusCode::BAD_REQUEST,
+                        String::from(r#"{"error":"UnreadableBody"}"#),
+                    ))
+                }
+            };
+            if let Some(response) = validate(&fields, &parts, &body) {
+                return Ok(response);
+            }
+            inner
+                .call(Request::from_parts(parts, Either::Left(Full::new(body))))
+                .await
+        })
+    }
+}
+
+/// The rejection for the first invalid field, `None` when all are valid or
+/// missing.
+fn validate<ResBody: From<String>>(
+    fields: &[TinField],
+    parts: &Parts,
+    body: &[u8],
+) -> Option<Response<ResBody>> {
+    let mut json = None;
+    for field in fields {
+        let text = match field {
+            TinField::Header(name) => parts.headers.get(name).map(|value| value.to_str().ok()),
+            TinField::Json(path) => {
+                let value = json.get_or_insert_with(|| {
+                    serde_json::from_slice::<Value>(body).unwrap_or(Value::Null)
+                });
+                json_value(value, path)
+            }
+        };
+        match text.map(|text| text.map(NorwegianTin::parse)) {
+            None | Some(Some(Ok(_))) => {}
+            Some(Some(Err(err))) => return Some(reject_invalid(field, &err)),
+            Some(None) => {
+                let body = format!(r#"{{"error":"UnexpectedType"{}}}"#, field_member(field));
+                return Some(reject(StatusCode::UNPROCESSABLE_ENTITY, body));
+            }
+        }
+    }
+    None
+}
+
+/// The value at `path`: `None` when it is missing or null, and `Some(None)`
+/// when it is not a string.
+fn json_value<'a>(value: &'a Value, path: &str) -> Option<Option<&'a str>> {
+    let field = if path.starts_with('/') {
+        value.pointer(path)
+    } else {
+        value.get(path)
+    };
+    field.filter(|field| !field.is_null()).map(Value::as_str)
+}
+
+fn reject_invalid<ResBody: From<String>>(
+    field: &TinField,
+    err: &NorwegianTinError,
+) -> Response<ResBody> {
+    let body = crate::http::error_body_with(err, &field_member(field));
+    let status = StatusCode::from_u16(crate::http::status_code(err))
+        .unwrap_or(StatusCode::UNPROCESSABLE_ENTITY);
+    reject(status, body)
+}
+
+/// The `field` member of a rejection body, e.g. `,"field":"x-customer-tin"`.
+fn field_member(field: &TinField) -> String {
+    let name = match field {
+        TinField::Header(name) => name.as_str(),
+        TinField::Json(path) => path.as_str(),
+    };
+    format!(r#","field":{}"#, Value::from(name))
+}
+
+fn reject<ResBody: From<String>>(status: StatusCode, body: String) -> Response<ResBody> {
+    let mut response = Response::new(ResBody::from(body));
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        http::HeaderValue::from_static("application/json"),
+    );
+    response
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+    use ::tower::{service_fn, ServiceExt};
+    use std::convert::Infallible;
+
+    type InnerBody = Either<Full<Bytes>, Full<Bytes>>;
+
+    async fn echo(request: Request<InnerBody>) -> Result<Response<Full<Bytes>>, Infallible> {
+        let body = request.into_body().collect().await.unwrap().to_bytes();
+        Ok(Response::new(Full::new(body)))
+    }
+
+    async fn call(request: Request<Full<Bytes>>) -> (StatusCode, String) {
+        let service = ValidateTinLayer::new()
+            .header(HeaderName::from_static("x-customer-tin"))
+            .json_field("/customer/tin")
+            .layer(service_fn(echo));
+        let response = service.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[::tokio::test]
+    async fn test_validate_tin_layer() {
+        let body = r#"{"customer":{"tin":"16057902284"}}"#;
+        let request = Request::post("/orders")
+            .header("x-customer-tin", "974760673")
+            .body(Full::from(body))
+            .unwrap();
+        assert_eq!(call(request).await, (StatusCode::OK, body.to_string()));
+
+        // Missing fields and bodies that are not JSON are let through
+        let request = Request::get("/orders").body(Full::from("")).unwrap();
+        assert_eq!(call(request).await.0, StatusCode::OK);
+
+        let request = Request::post("/orders")
+            .header("x-customer-tin", "12345678901")
+            .body(Full::from(body))
+            .unwrap();
+        assert_eq!(
+            call(request).await,
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                String::from(
+                    r#"{"error":"InvalidChecksum","code":"TIN_CHECKSUM","field":"x-customer-tin"}"#
+                )
+            )
+        );
+
+        let request = Request::post("/orders")
+            .body(Full::from(r#"{"customer":{"tin":"1605790228"}}"#))
+            .unwrap();
+        let (status, body) = call(request).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body.contains(r#""field":"/customer/tin""#));
+
+        // Present values that are not strings are rejected, null is missing
+        for json in [
+            r#"{"customer":{"tin":16057902284}}"#,
+            r#"{"customer":{"tin":["16057902284"]}}"#,
+        ] {
+            let request = Request::post("/orders").body(Full::from(json)).unwrap();
+            assert_eq!(
+                call(request).await,
+                (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    String::from(r#"{"error":"UnexpectedType","field":"/customer/tin"}"#)
+                ),
+                "{}",
+                json
+            );
+        }
+        let request = Request::post("/orders")
+            .header(
+                "x-customer-tin",
+                http::HeaderValue::from_bytes(b"16057902284\xff").unwrap(),
+            )
+            .body(Full::from(body))
+            .unwrap();
+        assert_eq!(
+            call(request).await,
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                String::from(r#"{"error":"UnexpectedType","field":"x-customer-tin"}"#)
+            )
+        );
+        let request = Request::post("/orders")
+            .body(Full::from(r#"{"customer":{"tin":null}}"#))
+            .unwrap();
+        assert_eq!(call(request).await.0, StatusCode::OK);
+    }
+
+    #[::tokio::test]
+    async fn test_header_only_body_not_buffered() {
+        let inner = service_fn(|request: Request<InnerBody>| async move {
+            let streamed = matches!(request.body(), Either::Right(_));
+            Ok::<_, Infallible>(Response::new(Full::<Bytes>::from(streamed.to_string())))
+        });
+        let service = ValidateTinLayer::new()
+            .header(HeaderName::from_static("x-customer-tin"))
+            .layer(inner);
+        let request = Request::post("/uploads")
+            .header("x-customer-tin", "974760673")
+            .body(Full::from("not json"))
+            .unwrap();
+        let response = service.oneshot(request).await.unwrap();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(body, "true");
+    }
+}
@@ -0,0 +1,112 @@
+use ::warp::http::StatusCode;
+use ::warp::reject::{self, Reject, Rejection};
+use ::warp::reply::{self, Reply};
+use ::warp::Filter;
+
+use crate::{NorwegianTin, NorwegianTinError};
+
+/// Rejection of `tin_param` and `tin_header` for a value that is not a
+/// valid number. `handle_rejection` turns it into a 422 response.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidTin(pub NorwegianTinError);
+
+impl Reject for InvalidTin {}
+
+/// Extracts and validates a `NorwegianTin` from the next path segment, e.g.
+/// `warp::path("persons").and(tin_param())`.
+pub fn tin_param() -> impl Filter<Extract = (NorwegianTin,), Error = Rejection> + Copy {
+    ::warp::path::param::<String>().and_then(|raw: String| async move { parse(&raw) })
+}
+
+/// Extracts and validates a `NorwegianTin` from the header `name`. A missing
+/// header is rejected like `warp::header`.
+pub fn tin_header(
+    name: &'static str,
+) -> impl Filter<Extract = (NorwegianTin,), Error = Rejection> + Copy {
+    ::warp::header::<String>(name).and_then(|raw: String| async move { parse(&raw) })
+}
+
+fn parse(raw: &str) -> Result<NorwegianTin, Rejection> {
+    NorwegianTin::parse(raw).map_err(|err| reject::custom(InvalidTin(err)))
+}
+
+/// For `Filter::recover`: responds to an `InvalidTin` with 422 Unprocessable
+/// Entity and a body like `{"error":"InvalidChecksum","code":"TIN_CHECKSUM"}`,
+/// and passes on other rejections.
+pub async fn handle_rejection(rejection: Rejection) -> Result<reply::Response, Rejection> {
+    match rejection.find::<InvalidTin>() {
+        Some(InvalidTin(err)) => Ok(error_response(err)),
+        None => Err(rejection),
+    }
+}
+
+fn error_response(err: &NorwegianTinError) -> reply::Response {
+    let body = crate::http::error_body(err);
+    let status = StatusCode::from_u16(crate::http::status_code(err))
+        .unwrap_or(StatusCode::UNPROCESSABLE_ENTITY);
+    reply::with_status(
+        reply::with_header(body, "content-type", "application/json"),
+        status,
+    )
+    .into_response()
+}
+
+/// Responds with the same body as `handle_rejection`.
+impl Reply for NorwegianTinError {
+    fn into_response(self) -> reply::Response {
+        error_response(&self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+
+    #[::tokio::test]
+    async fn test_tin_param() {
+        let route = ::warp::path("persons")
+            .and(tin_param())
+            .and(::warp::path::end())
+            .map(|tin: NorwegianTin| tin.to_string())
+            .recover(handle_rejection);
+
+        let response = ::warp::test::request()
+            .path("/persons/16057902284")
+            .reply(&route)
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.body(), "160579*****");
+
+        let response = ::warp::test::request()
+            .path("/persons/12345678901")
+            .reply(&route)
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            response.body(),
+            r#"{"error":"InvalidChecksum","code":"TIN_CHECKSUM"}"#
+        );
+    }
+
+    #[::tokio::test]
+    async fn test_tin_header() {
+        let filter = tin_header("x-customer-tin");
+        let tin = ::warp::test::request()
+            .header("x-customer-tin", "974760673")
+            .filter(&filter)
+            .await
+            .unwrap();
+        assert_eq!(tin, NorwegianTin::parse("974760673").unwrap());
+
+        let rejection = ::warp::test::request()
+            .header("x-customer-tin", "97476067")
+            .filter(&filter)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            rejection.find::<InvalidTin>(),
+            Some(&InvalidTin(NorwegianTinError::InvalidLength { found: 8 }))
+        );
+        assert!(::warp::test::request().filter(&filter).await.is_err());
+    }
+}